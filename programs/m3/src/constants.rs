@@ -2,8 +2,39 @@ use solana_program::{pubkey, pubkey::Pubkey};
 
 pub const PREFIX: &str = "m3";
 pub const SIGNER: &str = "signer";
+pub const SIGNED_ORDER: &str = "signed_order";
+pub const FEE_OVERRIDE: &str = "fee_override";
+pub const BUY_INTENT: &str = "buy_intent";
+pub const USER: &str = "user";
+pub const CONFIG: &str = "config";
+pub const POOL: &str = "pool";
+pub const POOL_ASSET: &str = "pool_asset";
+pub const BUNDLE: &str = "bundle";
+pub const SWAP: &str = "swap";
+pub const TRADER_STATS: &str = "trader_stats";
+pub const COLLECTION_OVERRIDE: &str = "collection_override";
+pub const BUYER_ESCROW: &str = "buyer_escrow";
+pub const ROYALTY_REDIRECT: &str = "royalty_redirect";
+pub const RENTAL: &str = "rental";
+pub const LOAN_OFFER: &str = "loan_offer";
+pub const LOAN: &str = "loan";
+pub const LAUNCHPAD: &str = "launchpad";
+pub const LAUNCHPAD_MINT_COUNT: &str = "launchpad_mint_count";
+pub const TRAIT_BID: &str = "trait_bid";
 pub const MAX_PRICE: u64 = 8000000 * 1000000000;
 pub const MAX_FEE_ABS_BP: i16 = 500;
+pub const MAX_LP_FEE_BP: u16 = 1000;
+pub const MAX_LOAN_INTEREST_BP: u16 = 10000;
+pub const MAX_SELLER_CANCEL_COOLDOWN_SECONDS: i64 = 30 * 86400;
+pub const MAX_PRICE_INCREASE_DELAY_SECONDS: i64 = 86400;
+pub const MAX_BUNDLE_SIZE: usize = 8;
+pub const MAX_SWAP_ITEMS: usize = 4;
+pub const MAX_FEE_TIERS: usize = 8;
+pub const MAX_FEE_RECIPIENTS: usize = 4;
+pub const MAX_FEE_CAPS: usize = 4;
+pub const MAX_METADATA_TEMPLATE_LEN: usize = 600;
+pub const MAX_LAUNCHPAD_PHASES: usize = 4;
+pub const VOLUME_WINDOW_DAYS: usize = 30;
 // Priviledged account for cancelling
 pub const CANCEL_AUTHORITY: Pubkey = if cfg!(feature = "anchor-test") {
     pubkey!("CcQQ9E8N1YDLY7dRffTzyACRPUDyv94UGdy6H2uiHycB")
@@ -11,6 +42,30 @@ pub const CANCEL_AUTHORITY: Pubkey = if cfg!(feature = "anchor-test") {
     pubkey!("CNTuB1JiQD8Xh5SoRcEmF61yivN9F7uzdSaGnRex36wi")
 };
 
+// Time a layaway buyer has to pay the remainder after putting down the deposit.
+pub const LAYAWAY_DURATION_SECONDS: i64 = if cfg!(feature = "anchor-test") {
+    5
+} else {
+    7 * 86400
+};
+// Smallest layaway deposit, as a share of the listing price.
+pub const LAYAWAY_MIN_DEPOSIT_BP: u64 = 1000;
+
+// Time a seller has to wait after requesting a cancel before withdrawing without the notary,
+// until the config authority sets another one.
+pub const DEFAULT_SELLER_CANCEL_COOLDOWN_SECONDS: i64 = if cfg!(feature = "anchor-test") {
+    5
+} else {
+    60 * 60 * 24
+};
+
+// Priviledged account for creating the program config, the config keeps its own authority after.
+pub const CONFIG_AUTHORITY: Pubkey = if cfg!(feature = "anchor-test") {
+    pubkey!("85WMa5UudXST3S2mKSqCgxS2RZ2xaa4YcmKD1auH7Ume")
+} else {
+    pubkey!("HU6puhfbN9CStkxUHk9gA2MxtTtD4ben6NARFVFyQf9R")
+};
+
 // Same one as M2 auctionhouse
 pub const ME_TREASURY: Pubkey = pubkey!("rFqFJ9g7TGBD8Ed7TPDnvGKZ5pWLPDyxLcvcH2eRCtt");
 
@@ -20,6 +75,15 @@ pub const ME_NOTARY: Pubkey = if cfg!(feature = "anchor-test") {
     pubkey!("NTYeYJ1wr4bpM5xo6zx5En44SvJFAd35zTxxNoERYqd")
 };
 
+// Bubblegum CPIs into Token Metadata to verify the collection of launchpad mints.
+pub const TOKEN_METADATA_PROGRAM: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+// Token Metadata's PDA seed for metadata accounts.
+pub const METADATA: &str = "metadata";
+// Key::MetadataV1, the first byte of a Token Metadata metadata account.
+pub const METADATA_V1_KEY: u8 = 4;
+// Bubblegum's PDA seed for signing collection verification.
+pub const BUBBLEGUM_COLLECTION_CPI: &str = "collection_cpi";
+
 pub const DEFAULT_MAKER_FEE_BP: i16 = 0;
 pub const DEFAULT_TAKER_FEE_BP: u16 = 250;
 
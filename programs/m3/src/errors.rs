@@ -44,4 +44,124 @@ pub enum ErrorCode {
     // 6014
     #[msg("SellerMismatch")]
     SellerMismatch,
+    // 6015
+    #[msg("Missing or invalid ed25519 signature instruction")]
+    InvalidSignature,
+    // 6016
+    #[msg("Order expired")]
+    OrderExpired,
+    // 6017
+    #[msg("Invalid payment mint")]
+    InvalidPaymentMint,
+    // 6018
+    #[msg("Order not expired")]
+    OrderNotExpired,
+    // 6019
+    #[msg("Order nonce is stale")]
+    StaleNonce,
+    // 6018
+    #[msg("Listing has a pending cancel request")]
+    CancelPending,
+    // 6019
+    #[msg("Cancel cooldown has not elapsed")]
+    CancelCooldownNotElapsed,
+    // 6020
+    #[msg("Invalid seller cancel cooldown")]
+    InvalidCancelCooldown,
+    // 6021
+    #[msg("Invalid pool parameters")]
+    InvalidPoolParams,
+    // 6022
+    #[msg("Asset is not part of the collection")]
+    InvalidCollection,
+    // 6023
+    #[msg("Insufficient pool balance")]
+    InsufficientPoolBalance,
+    // 6024
+    #[msg("Pool is not empty")]
+    PoolNotEmpty,
+    // 6025
+    #[msg("Price exceeds slippage limit")]
+    SlippageExceeded,
+    // 6026
+    #[msg("Invalid metadata args")]
+    InvalidMetadataArgs,
+    // 6026
+    #[msg("Invalid bundle size")]
+    InvalidBundleSize,
+    // 6027
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+    // 6028
+    #[msg("Invalid swap items")]
+    InvalidSwapItems,
+    // 6029
+    #[msg("Listing price version does not match")]
+    PriceVersionMismatch,
+    // 6030
+    #[msg("Invalid price increase delay")]
+    InvalidPriceIncreaseDelay,
+    // 6031
+    #[msg("Invalid fee tiers")]
+    InvalidFeeTiers,
+    // 6031
+    #[msg("Invalid fee recipients")]
+    InvalidFeeRecipients,
+    // 6032
+    #[msg("Invalid collection override")]
+    InvalidCollectionOverride,
+    // 6033
+    #[msg("Invalid fee caps")]
+    InvalidFeeCaps,
+    // 6034
+    #[msg("Invalid fee override")]
+    InvalidFeeOverride,
+    // 6035
+    #[msg("Insufficient buyer escrow balance")]
+    InsufficientEscrowBalance,
+    // 6036
+    #[msg("Listing is locked by a layaway")]
+    ListingLocked,
+    // 6037
+    #[msg("Invalid layaway")]
+    InvalidLayaway,
+    // 6038
+    #[msg("Invalid rental")]
+    InvalidRental,
+    // 6039
+    #[msg("Asset is currently rented")]
+    AssetRented,
+    // 6040
+    #[msg("Invalid loan offer")]
+    InvalidLoanOffer,
+    // 6041
+    #[msg("Loan is not due yet")]
+    LoanNotDue,
+    // 6042
+    #[msg("Loan is overdue")]
+    LoanOverdue,
+    // 6043
+    #[msg("Invalid launchpad")]
+    InvalidLaunchpad,
+    // 6044
+    #[msg("Launchpad sale has not started")]
+    SaleNotStarted,
+    // 6045
+    #[msg("Launchpad is sold out")]
+    SoldOut,
+    // 6046
+    #[msg("Launchpad phase is not active")]
+    PhaseNotActive,
+    // 6047
+    #[msg("Wallet is not on the allowlist")]
+    NotAllowlisted,
+    // 6048
+    #[msg("Wallet mint limit reached")]
+    WalletCapReached,
+    // 6049
+    #[msg("Invalid trait bid")]
+    InvalidTraitBid,
+    // 6050
+    #[msg("Asset is not eligible for the trait bid")]
+    AssetNotEligible,
 }
@@ -0,0 +1,38 @@
+use crate::{constants::*, errors::ErrorCode, states::UserState};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct BumpNonce<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+
+    #[account(
+      init_if_needed,
+      payer=wallet,
+      seeds=[PREFIX.as_bytes(), USER.as_bytes(), wallet.key().as_ref()],
+      space=UserState::LEN,
+      bump)]
+    user_state: Box<Account<'info, UserState>>,
+
+    system_program: Program<'info, System>,
+}
+
+// Invalidates every listing and signed order the wallet made with the current nonce.
+// Escrowed assets of invalidated listings can then be returned by anyone through cancel_sell.
+pub fn handle(ctx: Context<BumpNonce>) -> Result<()> {
+    let user_state = &mut ctx.accounts.user_state;
+    user_state.wallet = ctx.accounts.wallet.key();
+    user_state.bump = ctx.bumps.user_state;
+    user_state.nonce = user_state
+        .nonce
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    msg!(
+        "{{\"wallet\":\"{}\",\"nonce\":{}}}",
+        user_state.wallet,
+        user_state.nonce
+    );
+
+    Ok(())
+}
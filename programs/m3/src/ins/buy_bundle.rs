@@ -0,0 +1,299 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::utils::get_asset_id;
+use solana_program::{program::invoke, system_instruction};
+
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, BundleTradeState, Config, TraderStats, UserState},
+    utils::{
+        bundle_item_prices, calculate_item_fees, check_metadata_args, hash_metadata_data,
+        load_collection_override, pay_creator_fees, pay_platform_fees, take_accounts,
+        transfer_compressed_nft, verified_collection, verify_creators,
+    },
+};
+use anchor_lang::AnchorDeserialize;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuyBundleItemArgs {
+    // Following arguments are required for paying creator royalties
+    creator_shares: Vec<u16>,
+    creator_verified: Vec<bool>,
+    // Creator royalties. Validated against the metadata_hash by Bubblegum after hashing with metadata_hash.
+    seller_fee_basis_points: u16,
+
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // The Keccak256 hash of the NFTs existing metadata (without the verified flag for the creator changed).
+    // Does not include the extra seller-fee-basis-points hash that's required by Bubblegum.
+    // The metadata is retrieved from off-chain data store.
+    metadata_hash: [u8; 32],
+    // The Keccak256 hash of the NFTs existing creators array (without the verified flag for the creator changed).
+    // The creators array is retrieved from off-chain data store.
+    creator_hash: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // Number of proof accounts for this asset in remaining_accounts.
+    proof_len: u8,
+    // Borsh serialized MetadataArgs, checked against metadata_hash and creator_hash. Used to find
+    // the override of the asset's verified collection.
+    metadata_args: Vec<u8>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuyBundleArgs {
+    // Execute Sale Args
+    buyer_price: u64,
+    maker_fee_bp: i16,
+    taker_fee_bp: u16,
+    // In the same order as the bundle_trade_state items.
+    items: Vec<BuyBundleItemArgs>,
+}
+
+#[derive(Accounts)]
+#[instruction(args:BuyBundleArgs)]
+pub struct BuyBundle<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+    /// CHECK: seller checked in bundle_trade_state.
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    /// CHECK: meNotary constant
+    #[account(address = ME_NOTARY)]
+    notary: UncheckedAccount<'info>,
+    /// CHECK: that this matches hard-coded auction_house_treasury
+    #[account(mut, address = ME_TREASURY)]
+    platform_treasury: UncheckedAccount<'info>,
+    /// CHECK: program config, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()], bump)]
+    config: UncheckedAccount<'info>,
+
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    #[account(mut,
+      close=seller,
+      constraint= bundle_trade_state.seller_referral == seller_referral.key(),
+      constraint= bundle_trade_state.buyer_price == args.buyer_price,
+      constraint= bundle_trade_state.seller == seller.key(),
+      seeds=[
+          PREFIX.as_bytes(),
+          BUNDLE.as_bytes(),
+          seller.key().as_ref(),
+          bundle_trade_state.bundle_id.as_ref(),
+      ],
+      bump=bundle_trade_state.bump)]
+    bundle_trade_state: Box<Account<'info, BundleTradeState>>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+
+    /// CHECK: seller_referral
+    seller_referral: UncheckedAccount<'info>,
+
+    /// CHECK: only receives the assets.
+    receiver: UncheckedAccount<'info>,
+
+    /// CHECK: seller's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), seller.key().as_ref()], bump)]
+    seller_user_state: UncheckedAccount<'info>,
+
+    #[account(
+      init_if_needed,
+      payer=buyer,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), buyer.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    buyer_stats: Box<Account<'info, TraderStats>>,
+
+    #[account(
+      init_if_needed,
+      payer=buyer,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), seller.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    seller_stats: Box<Account<'info, TraderStats>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyBundle<'info>>,
+    args: BuyBundleArgs,
+) -> Result<()> {
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let notary = &ctx.accounts.notary;
+    let platform_treasury = &ctx.accounts.platform_treasury;
+    let bundle_trade_state = &ctx.accounts.bundle_trade_state;
+    let system_program = &ctx.accounts.system_program;
+
+    if bundle_trade_state.seller_nonce < UserState::current_nonce(&ctx.accounts.seller_user_state)?
+    {
+        return Err(ErrorCode::StaleNonce.into());
+    }
+    if args.items.len() != bundle_trade_state.items.len() {
+        return Err(ErrorCode::InvalidBundleSize.into());
+    }
+
+    // Royalties are paid per asset, on an even share of the bundle price.
+    let item_prices = bundle_item_prices(bundle_trade_state.items.len(), args.buyer_price);
+
+    let bump = ctx.bumps.program_as_signer;
+    let seeds = &[PREFIX.as_bytes(), SIGNER.as_bytes(), &[bump][..]];
+
+    // Remaining accounts are the fee recipient accounts, then for each item: tree_authority,
+    // merkle_tree, the creator hash and collection overrides, (optional) creator addresses, a
+    // (RoyaltyRedirect, payee) pair per creator and the Merkle proof path.
+    let config = Config::load(&ctx.accounts.config)?;
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let fee_recipient_accounts =
+        take_accounts(&mut remaining_accounts, config.fee_recipients.len())?;
+    let mut item_fees = Vec::with_capacity(item_prices.len());
+    for ((item, item_args), item_price) in bundle_trade_state
+        .items
+        .iter()
+        .zip(args.items)
+        .zip(item_prices)
+    {
+        let tree_accounts = take_accounts(&mut remaining_accounts, 4)?;
+        let creator_accounts =
+            take_accounts(&mut remaining_accounts, item_args.creator_shares.len())?;
+        let royalty_redirects =
+            take_accounts(&mut remaining_accounts, 2 * item_args.creator_shares.len())?;
+        let proof_path = take_accounts(&mut remaining_accounts, item_args.proof_len as usize)?;
+        let (tree_authority, merkle_tree) = (&tree_accounts[0], &tree_accounts[1]);
+        let (creator_hash_override, collection_override) = (&tree_accounts[2], &tree_accounts[3]);
+
+        if merkle_tree.key() != item.merkle_tree
+            || get_asset_id(merkle_tree.key, item_args.nonce) != item.asset_id
+        {
+            return Err(ErrorCode::AssetIDMismatch.into());
+        }
+
+        // Create data_hash from metadata_hash + seller_fee_basis_points (secures creator royalties)
+        let data_hash =
+            hash_metadata_data(item_args.metadata_hash, item_args.seller_fee_basis_points)?;
+
+        // 1: Pay Creator Fees, with the royalty share of the asset's override.
+        let metadata =
+            check_metadata_args(&item_args.metadata_args, data_hash, item_args.creator_hash)?;
+        let collection_override = load_collection_override(
+            creator_hash_override,
+            item_args.creator_hash,
+            collection_override,
+            verified_collection(&metadata),
+        )?;
+        verify_creators(
+            creator_accounts.iter(),
+            item_args.creator_shares.clone(),
+            item_args.creator_verified,
+            item_args.creator_hash,
+        )?;
+        pay_creator_fees(
+            &mut creator_accounts.iter(),
+            item_args.creator_shares,
+            royalty_redirects,
+            buyer,
+            system_program,
+            item_price,
+            collection_override.as_ref(),
+            item_args.seller_fee_basis_points,
+        )?;
+        item_fees.push((item_price, collection_override));
+
+        // 2. Transfer NFT to Buyer
+        transfer_compressed_nft(
+            tree_authority,
+            &ctx.accounts.program_as_signer.to_account_info(),
+            &ctx.accounts.program_as_signer.to_account_info(), // delegate
+            &ctx.accounts.receiver.to_account_info(),
+            merkle_tree,
+            &ctx.accounts.log_wrapper,
+            &ctx.accounts.compression_program,
+            &ctx.accounts.system_program,
+            proof_path,
+            ctx.accounts.bubblegum_program.key(),
+            item_args.root,
+            data_hash,
+            item_args.creator_hash, // This is secured by Bubblegum (important for paying creators)
+            item_args.nonce,
+            item.index,
+            Some(seeds),
+        )?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.buyer_stats.roll(now);
+    ctx.accounts.seller_stats.roll(now);
+    let tier_fee_bp = config.tier_fee_bp(
+        ctx.accounts.seller_stats.rolling_volume(),
+        ctx.accounts.buyer_stats.rolling_volume(),
+    );
+
+    // 3: Calculate fees, per asset on its share of the price with the asset's override.
+    let fees = calculate_item_fees(
+        notary,
+        &item_fees,
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        buyer,
+        seller,
+        tier_fee_bp,
+        config.fee_cap(&bundle_trade_state.payment_mint),
+    )?;
+
+    // 4: Buyer pays Seller
+    invoke(
+        &system_instruction::transfer(&buyer.key(), seller.key, fees.seller_will_get_from_buyer),
+        &[
+            buyer.to_account_info(),
+            seller.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    // 5. Buyer pays Treasury, split between the configured fee recipients.
+    pay_platform_fees(
+        buyer,
+        &platform_treasury.to_account_info(),
+        fee_recipient_accounts,
+        &config.fee_recipients,
+        system_program,
+        fees.total_platform_fee,
+    )?;
+
+    // Trading against yourself doesn't count towards the fee tiers.
+    let volume = if buyer.key() != seller.key() {
+        args.buyer_price
+    } else {
+        0
+    };
+    let buyer_bump = ctx.bumps.buyer_stats;
+    ctx.accounts
+        .buyer_stats
+        .record_fill(buyer.key(), buyer_bump, now, volume);
+    let seller_bump = ctx.bumps.seller_stats;
+    ctx.accounts
+        .seller_stats
+        .record_fill(seller.key(), seller_bump, now, volume);
+
+    msg!(
+        "{{\"price\":{},\"maker_fee\":{},\"taker_fee\":{},\"total_platform_fee\":{}}}",
+        args.buyer_price,
+        fees.maker_fee,
+        fees.taker_fee,
+        fees.total_platform_fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,315 @@
+use anchor_lang::prelude::*;
+use solana_program::{program::invoke, system_instruction};
+
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, Config, Pool, PoolAsset, TraderStats, TreeConfigAnchor},
+    utils::{
+        calculate_fees, curve_price_up, get_lp_fee, hash_metadata_data, load_collection_override,
+        pay_creator_fees, pay_platform_fees, take_accounts, transfer_compressed_nft,
+        verify_creators,
+    },
+};
+use anchor_lang::AnchorDeserialize;
+use mpl_bubblegum::utils::get_asset_id;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuyFromPoolArgs {
+    // Most the buyer is willing to pay the pool, including the LP fee.
+    max_price: u64,
+    // Pools are the maker side, only the taker fee applies.
+    taker_fee_bp: u16,
+    // Following arguments are required for paying creator royalties
+    creator_shares: Vec<u16>,
+    creator_verified: Vec<bool>,
+    // Creator royalties. Validated against the metadata_hash by Bubblegum after hashing with metadata_hash.
+    seller_fee_basis_points: u16,
+
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // The Keccak256 hash of the NFTs existing metadata (without the verified flag for the creator changed).
+    // Does not include the extra seller-fee-basis-points hash that's required by Bubblegum.
+    // The metadata is retrieved from off-chain data store.
+    metadata_hash: [u8; 32],
+    // The Keccak256 hash of the NFTs existing creators array (without the verified flag for the creator changed).
+    // The creators array is retrieved from off-chain data store.
+    creator_hash: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args:BuyFromPoolArgs)]
+pub struct BuyFromPool<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+    /// CHECK: meNotary constant
+    #[account(address = ME_NOTARY)]
+    notary: UncheckedAccount<'info>,
+    /// CHECK: that this matches hard-coded auction_house_treasury
+    #[account(mut, address = ME_TREASURY)]
+    platform_treasury: UncheckedAccount<'info>,
+    /// CHECK: program config, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()], bump)]
+    config: UncheckedAccount<'info>,
+    /// CHECK: override keyed by the asset's creator hash, may not be initialized.
+    #[account(
+      seeds=[PREFIX.as_bytes(), COLLECTION_OVERRIDE.as_bytes(), args.creator_hash.as_ref()],
+      bump)]
+    creator_hash_override: UncheckedAccount<'info>,
+    /// CHECK: override keyed by the pool's collection, may not be initialized. Checked in
+    /// load_collection_override.
+    collection_override: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL.as_bytes(),
+          pool.owner.as_ref(),
+          pool.uuid.as_ref(),
+      ],
+      bump=pool.bump)]
+    pool: Box<Account<'info, Pool>>,
+
+    #[account(
+      mut,
+      close=rent_payer,
+      has_one=pool,
+      has_one=rent_payer,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL_ASSET.as_bytes(),
+          merkle_tree.key().as_ref(),
+          args.index.to_le_bytes().as_ref(),
+      ],
+      bump=pool_asset.bump)]
+    pool_asset: Box<Account<'info, PoolAsset>>,
+
+    /// CHECK: checked against pool_asset, gets the pool_asset rent back.
+    #[account(mut)]
+    rent_payer: UncheckedAccount<'info>,
+
+    // ==== cNFT transfer args ==== //
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        seeds::program = bubblegum_program.key(),
+        bump,
+      )]
+    /// CHECK: This account is neither written to nor read from.
+    pub tree_authority: Account<'info, TreeConfigAnchor>,
+    // The account that contains the Merkle tree, initialized by create_tree.
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+
+    /// CHECK: only receives the asset.
+    receiver: UncheckedAccount<'info>,
+
+    #[account(
+      init_if_needed,
+      payer=buyer,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), buyer.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    buyer_stats: Box<Account<'info, TraderStats>>,
+
+    #[account(
+      init_if_needed,
+      payer=buyer,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), pool.owner.as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    pool_owner_stats: Box<Account<'info, TraderStats>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyFromPool<'info>>,
+    args: BuyFromPoolArgs,
+) -> Result<()> {
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let pool_ai = &ctx.accounts.pool.to_account_info();
+    let notary = &ctx.accounts.notary;
+    let platform_treasury = &ctx.accounts.platform_treasury;
+    let system_program = &ctx.accounts.system_program;
+    let pool = &mut ctx.accounts.pool;
+
+    if get_asset_id(&ctx.accounts.merkle_tree.key(), args.nonce) != ctx.accounts.pool_asset.asset_id
+    {
+        return Err(ErrorCode::AssetIDMismatch.into());
+    }
+
+    let price = pool.spot_price;
+    let lp_fee = get_lp_fee(price, pool.lp_fee_bp)?;
+    let price_with_lp_fee = price
+        .checked_add(lp_fee)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    if price_with_lp_fee > args.max_price {
+        msg!(
+            "Pool price {} exceeds max price {}",
+            price_with_lp_fee,
+            args.max_price
+        );
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+
+    // Remaining accounts are 1. (Optional) creator addresses, 2. a (RoyaltyRedirect, payee) pair
+    // per creator, 3. fee recipient accounts and 4. Merkle proof path.
+    let creator_shares_length = args.creator_shares.len();
+    let creator_shares_clone = args.creator_shares.clone();
+    let config = Config::load(&ctx.accounts.config)?;
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let creator_accounts = take_accounts(&mut remaining_accounts, creator_shares_length)?;
+    let royalty_redirects = take_accounts(&mut remaining_accounts, 2 * creator_shares_length)?;
+    let fee_recipient_accounts =
+        take_accounts(&mut remaining_accounts, config.fee_recipients.len())?;
+    let proof_path = remaining_accounts;
+
+    // Create data_hash from metadata_hash + seller_fee_basis_points (secures creator royalties)
+    let data_hash = hash_metadata_data(args.metadata_hash, args.seller_fee_basis_points)?;
+
+    // The asset's override replaces the default royalty share and fees. Pool assets were checked
+    // to be verified members of the pool's collection on deposit.
+    let collection_override = load_collection_override(
+        &ctx.accounts.creator_hash_override,
+        args.creator_hash,
+        &ctx.accounts.collection_override,
+        Some(&pool.collection),
+    )?;
+
+    // 1: Pay Creator Fees
+    verify_creators(
+        creator_accounts.iter(),
+        args.creator_shares,
+        args.creator_verified,
+        args.creator_hash,
+    )?;
+    pay_creator_fees(
+        &mut creator_accounts.iter(),
+        creator_shares_clone,
+        royalty_redirects,
+        buyer,
+        system_program,
+        price,
+        collection_override.as_ref(),
+        args.seller_fee_basis_points,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.buyer_stats.roll(now);
+    ctx.accounts.pool_owner_stats.roll(now);
+    let tier_fee_bp = config.tier_fee_bp(
+        ctx.accounts.pool_owner_stats.rolling_volume(),
+        ctx.accounts.buyer_stats.rolling_volume(),
+    );
+
+    // 2: Calculate fees
+    let fees = calculate_fees(
+        notary,
+        price,
+        0,
+        args.taker_fee_bp,
+        buyer,
+        pool_ai,
+        tier_fee_bp,
+        collection_override.as_ref(),
+        config.fee_cap(&spl_token::native_mint::ID),
+    )?;
+
+    // 3: Buyer pays the pool, including the LP fee
+    let pool_gets = fees
+        .seller_will_get_from_buyer
+        .checked_add(lp_fee)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    invoke(
+        &system_instruction::transfer(&buyer.key(), pool_ai.key, pool_gets),
+        &[
+            buyer.to_account_info(),
+            pool_ai.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    // 4. Buyer pays Treasury, split between the configured fee recipients.
+    pay_platform_fees(
+        buyer,
+        &platform_treasury.to_account_info(),
+        fee_recipient_accounts,
+        &config.fee_recipients,
+        system_program,
+        fees.total_platform_fee,
+    )?;
+
+    // 5. Transfer NFT to Buyer
+    let bump = ctx.bumps.program_as_signer;
+    let seeds = &[PREFIX.as_bytes(), SIGNER.as_bytes(), &[bump][..]];
+    transfer_compressed_nft(
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.program_as_signer.to_account_info(),
+        &ctx.accounts.program_as_signer.to_account_info(), // delegate
+        &ctx.accounts.receiver.to_account_info(),
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        &ctx.accounts.system_program,
+        proof_path,
+        ctx.accounts.bubblegum_program.key(),
+        args.root,
+        data_hash,
+        args.creator_hash, // This is secured by Bubblegum (important for paying creators)
+        args.nonce,
+        args.index,
+        Some(seeds),
+    )?;
+
+    // 6. Move the curve up
+    pool.spot_price = curve_price_up(pool.curve_type, pool.spot_price, pool.delta)?;
+    pool.nft_count = pool
+        .nft_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    pool.updated_at = now;
+
+    // Trading against yourself doesn't count towards the fee tiers.
+    let volume = if buyer.key() != pool.owner { price } else { 0 };
+    let buyer_bump = ctx.bumps.buyer_stats;
+    ctx.accounts
+        .buyer_stats
+        .record_fill(buyer.key(), buyer_bump, now, volume);
+    let pool_owner_bump = ctx.bumps.pool_owner_stats;
+    ctx.accounts
+        .pool_owner_stats
+        .record_fill(pool.owner, pool_owner_bump, now, volume);
+
+    msg!(
+        "{{\"price\":{},\"lp_fee\":{},\"taker_fee\":{},\"total_platform_fee\":{},\"spot_price\":{}}}",
+        price,
+        lp_fee,
+        fees.taker_fee,
+        fees.total_platform_fee,
+        pool.spot_price
+    );
+
+    Ok(())
+}
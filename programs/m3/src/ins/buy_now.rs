@@ -1,21 +1,82 @@
 use anchor_lang::prelude::*;
-use solana_program::{program::invoke, system_instruction};
+use solana_program::sysvar;
 
 use crate::{
     constants::*,
-    states::{BubblegumProgram, SellerTradeState, TreeConfigAnchor},
+    errors::ErrorCode,
+    states::{
+        BubblegumProgram, BuyerEscrow, Config, FeeOverrideReceipt, SellerTradeState, TraderStats,
+        TreeConfigAnchor, UserState,
+    },
     utils::{
-        calculate_fees, hash_metadata_data, pay_creator_fees, transfer_compressed_nft,
-        verify_creators,
+        hash_metadata_data, pay_for_listing, settle_buyer_escrow, transfer_compressed_nft,
+        verify_ed25519_ix, PurchaseAccounts, PurchaseTerms,
     },
 };
 use anchor_lang::AnchorDeserialize;
 use spl_account_compression::{program::SplAccountCompression, Noop};
 
+// Custom fees the notary signs off-chain instead of co-signing the transaction. The signed
+// message is the program id and FEE_OVERRIDE followed by the borsh serialized override, so it's
+// only valid for this deployment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FeeOverride {
+    buyer: Pubkey,
+    asset_id: Pubkey,
+    maker_fee_bp: i16,
+    taker_fee_bp: u16,
+    // Unix timestamp after which the override can no longer be used.
+    expiry: i64,
+    // Chosen by the notary, an override with the same nonce can only be used once.
+    nonce: u64,
+}
+
+impl FeeOverride {
+    pub fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    // The (maker, taker) bp of an override the notary signed for this buyer and asset. The
+    // override is recorded in its receipt, which fails to initialize if it was used before.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify(
+        &self,
+        instructions: &AccountInfo,
+        notary: &Pubkey,
+        buyer: &Pubkey,
+        asset_id: &Pubkey,
+        now: i64,
+        receipt: Option<&mut FeeOverrideReceipt>,
+        receipt_bump: u8,
+        rent_payer: &Pubkey,
+    ) -> Result<(i16, u16)> {
+        if self.buyer != *buyer || self.asset_id != *asset_id || now > self.expiry {
+            return Err(ErrorCode::InvalidFeeOverride.into());
+        }
+        let message = [
+            crate::ID.as_ref(),
+            FEE_OVERRIDE.as_bytes(),
+            &self.try_to_vec()?,
+        ]
+        .concat();
+        verify_ed25519_ix(instructions, notary, &message)?;
+
+        let receipt = receipt.ok_or(ErrorCode::InvalidFeeOverride)?;
+        receipt.nonce = self.nonce;
+        receipt.bump = receipt_bump;
+        receipt.buyer = self.buyer;
+        receipt.asset_id = self.asset_id;
+        receipt.rent_payer = *rent_payer;
+        receipt.expiry = self.expiry;
+        Ok((self.maker_fee_bp, self.taker_fee_bp))
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BuyNowArgs {
     // Execute Sale Args
-    buyer_price: u64,
+    // Most the buyer is willing to pay, the sale settles at the listed price if it's not higher.
+    max_price: u64,
     maker_fee_bp: i16,
     taker_fee_bp: u16,
     // This argument is ignored, 100% royalties are enforced by the contract
@@ -43,6 +104,13 @@ pub struct BuyNowArgs {
     nonce: u64,
     // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
     index: u32,
+    // Optionally pins the listing's price_version, so the buy fails if the seller repriced.
+    price_version: Option<u64>,
+    // Borsh serialized MetadataArgs, checked against metadata_hash and creator_hash. Used to find
+    // the override of the asset's verified collection.
+    metadata_args: Vec<u8>,
+    // Notary signed fees, used when the notary doesn't sign the transaction itself.
+    fee_override: Option<FeeOverride>,
 }
 
 #[derive(Accounts)]
@@ -84,9 +152,8 @@ pub struct BuyNow<'info> {
     compression_program: Program<'info, SplAccountCompression>,
 
     #[account(mut,
-      close=seller,
+      close=rent_payer,
       constraint= seller_trade_state.seller_referral == seller_referral.key(),
-      constraint= seller_trade_state.buyer_price == args.buyer_price,
       constraint= seller_trade_state.seller == seller.key(),
       seeds=[
           PREFIX.as_bytes(),
@@ -103,82 +170,212 @@ pub struct BuyNow<'info> {
 
     /// CHECK: only receives the asset.
     receiver: UncheckedAccount<'info>,
+
+    /// CHECK: seller's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), seller.key().as_ref()], bump)]
+    seller_user_state: UncheckedAccount<'info>,
+
+    /// CHECK: receives the sale proceeds, checked against seller_trade_state.
+    #[account(mut, address = seller_trade_state.payout_address())]
+    payout_address: UncheckedAccount<'info>,
+
+    /// CHECK: gets the seller_trade_state rent back, checked against seller_trade_state.
+    #[account(mut, address = seller_trade_state.rent_payer())]
+    rent_payer: UncheckedAccount<'info>,
+
+    /// CHECK: program config, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()], bump)]
+    config: UncheckedAccount<'info>,
+
+    #[account(
+      init_if_needed,
+      payer=buyer,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), buyer.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    buyer_stats: Box<Account<'info, TraderStats>>,
+
+    #[account(
+      init_if_needed,
+      payer=buyer,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), seller.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    seller_stats: Box<Account<'info, TraderStats>>,
+
+    /// CHECK: override keyed by the asset's creator hash, may not be initialized.
+    #[account(
+      seeds=[PREFIX.as_bytes(), COLLECTION_OVERRIDE.as_bytes(), args.creator_hash.as_ref()],
+      bump)]
+    creator_hash_override: UncheckedAccount<'info>,
+
+    /// CHECK: override keyed by the asset's verified collection, may not be initialized. Checked
+    /// against metadata_args in load_collection_override.
+    collection_override: UncheckedAccount<'info>,
+
+    /// CHECK: instructions sysvar, used to find the notary's ed25519 signature of a fee_override.
+    #[account(address = sysvar::instructions::ID)]
+    instructions: UncheckedAccount<'info>,
+
+    // When passed, the purchase is paid out of the buyer's escrow instead of their wallet.
+    #[account(
+      mut,
+      seeds=[PREFIX.as_bytes(), BUYER_ESCROW.as_bytes(), buyer.key().as_ref()],
+      bump=buyer_escrow.bump)]
+    buyer_escrow: Option<Box<Account<'info, BuyerEscrow>>>,
+
+    // Required with a fee_override the notary didn't co-sign, marks its nonce as used.
+    #[account(
+      init,
+      payer=buyer,
+      seeds=[
+          PREFIX.as_bytes(),
+          FEE_OVERRIDE.as_bytes(),
+          args.fee_override.as_ref().map_or(0, FeeOverride::nonce).to_le_bytes().as_ref(),
+      ],
+      space=FeeOverrideReceipt::LEN,
+      bump)]
+    fee_override_receipt: Option<Box<Account<'info, FeeOverrideReceipt>>>,
 }
 
 pub fn handle<'info>(
     ctx: Context<'_, '_, '_, 'info, BuyNow<'info>>,
     args: BuyNowArgs,
-) -> Result<()> {
+) -> Result<u64> {
     let buyer = &ctx.accounts.buyer.to_account_info();
     let seller = &ctx.accounts.seller.to_account_info();
+    let payout_address = &ctx.accounts.payout_address.to_account_info();
     let notary = &ctx.accounts.notary;
     let platform_treasury = &ctx.accounts.platform_treasury;
-    let _seller_trade_state = &mut ctx.accounts.seller_trade_state;
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
     let system_program = &ctx.accounts.system_program;
-    let _program_as_signer = &ctx.accounts.program_as_signer;
 
-    // Remaining accounts are 1. (Optional) creator addresses and 2. Merkle proof path.
-    let creator_shares_length = args.creator_shares.len();
-    let creator_shares_clone = args.creator_shares.clone();
-    let (creator_accounts, proof_path) = ctx.remaining_accounts.split_at(creator_shares_length);
+    // A locked listing can only be bought by the layaway buyer before the deadline. The seller
+    // can't back out of a layaway with a nonce bump or cancel request.
+    let now = Clock::get()?.unix_timestamp;
+    let layaway_deposit = if seller_trade_state.is_locked() {
+        if seller_trade_state.layaway_buyer != buyer.key()
+            || now > seller_trade_state.layaway_deadline
+        {
+            return Err(ErrorCode::ListingLocked.into());
+        }
+        seller_trade_state.layaway_deposit
+    } else {
+        // Listings made before the seller bumped their nonce are cancelled.
+        if seller_trade_state.seller_nonce
+            < UserState::current_nonce(&ctx.accounts.seller_user_state)?
+        {
+            return Err(ErrorCode::StaleNonce.into());
+        }
+        // Listings are frozen once the seller requested a cancel.
+        if seller_trade_state.cancel_requested_at != 0 {
+            return Err(ErrorCode::CancelPending.into());
+        }
+        0
+    };
 
-    // Create data_hash from metadata_hash + seller_fee_basis_points (secures creator royalties)
-    let data_hash = hash_metadata_data(args.metadata_hash, args.seller_fee_basis_points)?;
+    if let Some(price_version) = args.price_version {
+        if price_version != seller_trade_state.price_version {
+            return Err(ErrorCode::PriceVersionMismatch.into());
+        }
+    }
+    seller_trade_state.apply_pending_price(now);
+    let buyer_price = seller_trade_state.buyer_price;
+    if buyer_price > args.max_price {
+        msg!(
+            "Listing price {} exceeds max price {}",
+            buyer_price,
+            args.max_price
+        );
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
 
-    // 1: Pay Creator Fees
-    // Bubblegum will verify the creator_hash for us, but we should verify the input creators match.
-    verify_creators(
-        creator_accounts.iter(),
-        args.creator_shares,
-        args.creator_verified,
-        args.creator_hash,
-    )?;
-    pay_creator_fees(
-        &mut creator_accounts.iter(),
-        creator_shares_clone,
-        &buyer.to_account_info(),
-        system_program,
-        args.buyer_price,
-        DEFAULT_CREATOR_ROYALTY_BP,
-        args.seller_fee_basis_points,
-    )?;
+    // The layaway deposit goes back to the buyer, who then pays the full price as usual.
+    if layaway_deposit > 0 {
+        **seller_trade_state
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= layaway_deposit;
+        **buyer.try_borrow_mut_lamports()? += layaway_deposit;
+        seller_trade_state.clear_layaway();
+    }
 
-    // 2: Calculate fees
-    let fees = calculate_fees(
-        notary,
-        args.buyer_price,
-        args.maker_fee_bp,
-        args.taker_fee_bp,
-        buyer,
-        seller,
-    )?;
+    // Paying from the escrow: seller proceeds, royalties and fees are debited from it directly.
+    let payer = &match &ctx.accounts.buyer_escrow {
+        Some(buyer_escrow) => buyer_escrow.to_account_info(),
+        None => buyer.clone(),
+    };
+    let payer_lamports_before = payer.lamports();
 
-    // 3: Buyer pays Seller
-    invoke(
-        &system_instruction::transfer(&buyer.key(), seller.key, fees.seller_will_get_from_buyer),
-        &[
-            buyer.to_account_info(),
-            seller.to_account_info(),
-            system_program.to_account_info(),
-        ],
+    // Create data_hash from metadata_hash + seller_fee_basis_points (secures creator royalties)
+    let data_hash = hash_metadata_data(args.metadata_hash, args.seller_fee_basis_points)?;
+
+    let fee_override_bp = match &args.fee_override {
+        Some(fee_override) if !notary.is_signer => Some(
+            fee_override.verify(
+                &ctx.accounts.instructions,
+                notary.key,
+                buyer.key,
+                &seller_trade_state.asset_id,
+                now,
+                ctx.accounts
+                    .fee_override_receipt
+                    .as_mut()
+                    .map(|receipt| &mut ***receipt),
+                ctx.bumps.fee_override_receipt,
+                buyer.key,
+            )?,
+        ),
+        _ if ctx.accounts.fee_override_receipt.is_some() => {
+            return Err(ErrorCode::InvalidFeeOverride.into())
+        }
+        _ => None,
+    };
+    let buyer_stats = &mut ctx.accounts.buyer_stats;
+    let seller_stats = &mut ctx.accounts.seller_stats;
+    buyer_stats.roll(now);
+    seller_stats.roll(now);
+    let config = Config::load(&ctx.accounts.config)?;
+    // 1-4: Pay Creator Fees, Seller and Treasury out of the buyer's wallet or escrow.
+    let payments = pay_for_listing(
+        &PurchaseAccounts {
+            payer,
+            buyer,
+            seller,
+            payout_address,
+            notary,
+            platform_treasury,
+            system_program,
+            creator_hash_override: &ctx.accounts.creator_hash_override,
+            collection_override: &ctx.accounts.collection_override,
+        },
+        PurchaseTerms {
+            buyer_price,
+            maker_fee_bp: args.maker_fee_bp,
+            taker_fee_bp: args.taker_fee_bp,
+            creator_shares: args.creator_shares,
+            creator_verified: args.creator_verified,
+            seller_fee_basis_points: args.seller_fee_basis_points,
+            data_hash,
+            creator_hash: args.creator_hash,
+            metadata_args: &args.metadata_args,
+            fee_override_bp,
+            tier_fee_bp: config
+                .tier_fee_bp(seller_stats.rolling_volume(), buyer_stats.rolling_volume()),
+        },
+        ctx.remaining_accounts,
+        &config,
+        &seller_trade_state.payment_mint,
     )?;
+    let fees = &payments.fees;
 
-    // 4. Buyer pays Treasury
-    let treasury_clone = platform_treasury.to_account_info();
-    if fees.total_platform_fee > 0 {
-        invoke(
-            &system_instruction::transfer(
-                &buyer.key(),
-                treasury_clone.key,
-                fees.total_platform_fee,
-            ),
-            &[
-                buyer.to_account_info(),
-                treasury_clone.to_account_info(),
-                system_program.to_account_info(),
-            ],
-        )?;
-    }
+    // Buying your own listing doesn't count towards the fee tiers.
+    let volume = if buyer.key != seller.key {
+        buyer_price
+    } else {
+        0
+    };
+    buyer_stats.record_fill(buyer.key(), ctx.bumps.buyer_stats, now, volume);
+    seller_stats.record_fill(seller.key(), ctx.bumps.seller_stats, now, volume);
 
     // 5. Transfer NFT to Buyer
     let bump = ctx.bumps.program_as_signer;
@@ -193,7 +390,7 @@ pub fn handle<'info>(
         &ctx.accounts.log_wrapper,
         &ctx.accounts.compression_program,
         &ctx.accounts.system_program,
-        proof_path,
+        payments.proof_path,
         ctx.accounts.bubblegum_program.key(),
         args.root,
         data_hash,
@@ -203,13 +400,20 @@ pub fn handle<'info>(
         Some(seeds),
     )?;
 
+    let escrow_spent = match &ctx.accounts.buyer_escrow {
+        Some(_) => settle_buyer_escrow(payer, payer_lamports_before)?,
+        None => 0,
+    };
+
     msg!(
-        "{{\"price\":{},\"maker_fee\":{},\"taker_fee\":{},\"total_platform_fee\":{}}}",
-        args.buyer_price,
+        "{{\"price\":{},\"maker_fee\":{},\"taker_fee\":{},\"total_platform_fee\":{},\"fee_recipients\":[{}],\"escrow_spent\":{}}}",
+        buyer_price,
         fees.maker_fee,
         fees.taker_fee,
-        fees.total_platform_fee
+        fees.total_platform_fee,
+        payments.fee_recipients_log(),
+        escrow_spent
     );
 
-    Ok(())
+    Ok(buyer_price)
 }
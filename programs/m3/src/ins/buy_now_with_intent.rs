@@ -0,0 +1,394 @@
+use anchor_lang::prelude::*;
+use solana_program::sysvar;
+
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    ins::FeeOverride,
+    states::{
+        BubblegumProgram, BuyIntentReceipt, BuyerEscrow, Config, FeeOverrideReceipt,
+        SellerTradeState, TraderStats, TreeConfigAnchor, UserState,
+    },
+    utils::{
+        hash_metadata_data, pay_for_listing, settle_buyer_escrow, transfer_compressed_nft,
+        verify_ed25519_ix, PurchaseAccounts, PurchaseTerms,
+    },
+};
+use anchor_lang::AnchorDeserialize;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+// The purchase the buyer signs off-chain for a relayer to execute. The signed message is the
+// program id and BUY_INTENT followed by the borsh serialized intent, so it's only valid for this
+// deployment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BuyIntent {
+    asset_id: Pubkey,
+    // Most the buyer is willing to pay, the sale settles at the listed price if it's not higher.
+    max_price: u64,
+    receiver: Pubkey,
+    // Unix timestamp after which the intent can no longer be used.
+    expiry: i64,
+    // Chosen by the buyer, an intent with the same nonce can only be used once.
+    nonce: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuyNowWithIntentArgs {
+    intent: BuyIntent,
+
+    // Execute Sale Args
+    maker_fee_bp: i16,
+    taker_fee_bp: u16,
+    // Following arguments are required for paying creator royalties
+    creator_shares: Vec<u16>,
+    creator_verified: Vec<bool>,
+    // Creator royalties. Validated against the metadata_hash by Bubblegum after hashing with metadata_hash.
+    seller_fee_basis_points: u16,
+
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // The Keccak256 hash of the NFTs existing metadata (without the verified flag for the creator changed).
+    // Does not include the extra seller-fee-basis-points hash that's required by Bubblegum.
+    // The metadata is retrieved from off-chain data store.
+    metadata_hash: [u8; 32],
+    // The Keccak256 hash of the NFTs existing creators array (without the verified flag for the creator changed).
+    // The creators array is retrieved from off-chain data store.
+    creator_hash: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+    // Borsh serialized MetadataArgs, checked against metadata_hash and creator_hash. Used to find
+    // the override of the asset's verified collection.
+    metadata_args: Vec<u8>,
+    // Notary signed fees, used when the notary doesn't sign the transaction itself.
+    fee_override: Option<FeeOverride>,
+}
+
+#[derive(Accounts)]
+#[instruction(args:BuyNowWithIntentArgs)]
+pub struct BuyNowWithIntent<'info> {
+    // Submits the transaction and pays its fees and rent, the purchase itself is paid from
+    // the buyer's escrow.
+    #[account(mut)]
+    relayer: Signer<'info>,
+    /// CHECK: buyer is the signer of the intent, checked against the ed25519 instruction.
+    buyer: UncheckedAccount<'info>,
+    /// CHECK: seller checked in seller_trade_state.
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    /// CHECK: meNotary constant
+    #[account(address = ME_NOTARY)]
+    notary: UncheckedAccount<'info>,
+    /// CHECK: that this matches hard-coded auction_house_treasury
+    #[account(mut, address = ME_TREASURY)]
+    platform_treasury: UncheckedAccount<'info>,
+
+    // ==== cNFT transfer args ==== //
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        seeds::program = bubblegum_program.key(),
+        bump,
+      )]
+    /// CHECK: This account is neither written to nor read from.
+    pub tree_authority: Account<'info, TreeConfigAnchor>,
+    // The account that contains the Merkle tree, initialized by create_tree.
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    #[account(mut,
+      close=rent_payer,
+      constraint= seller_trade_state.seller_referral == seller_referral.key(),
+      constraint= seller_trade_state.seller == seller.key(),
+      seeds=[
+          PREFIX.as_bytes(),
+          merkle_tree.key().as_ref(),
+          args.index.to_le_bytes().as_ref(),
+      ], bump)]
+    seller_trade_state: Box<Account<'info, SellerTradeState>>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+
+    /// CHECK: seller_referral
+    seller_referral: UncheckedAccount<'info>,
+
+    /// CHECK: only receives the asset, checked against the intent.
+    #[account(address = args.intent.receiver)]
+    receiver: UncheckedAccount<'info>,
+
+    /// CHECK: seller's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), seller.key().as_ref()], bump)]
+    seller_user_state: UncheckedAccount<'info>,
+
+    /// CHECK: receives the sale proceeds, checked against seller_trade_state.
+    #[account(mut, address = seller_trade_state.payout_address())]
+    payout_address: UncheckedAccount<'info>,
+
+    /// CHECK: gets the seller_trade_state rent back, checked against seller_trade_state.
+    #[account(mut, address = seller_trade_state.rent_payer())]
+    rent_payer: UncheckedAccount<'info>,
+
+    /// CHECK: program config, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()], bump)]
+    config: UncheckedAccount<'info>,
+
+    #[account(
+      init_if_needed,
+      payer=relayer,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), buyer.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    buyer_stats: Box<Account<'info, TraderStats>>,
+
+    #[account(
+      init_if_needed,
+      payer=relayer,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), seller.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    seller_stats: Box<Account<'info, TraderStats>>,
+
+    /// CHECK: override keyed by the asset's creator hash, may not be initialized.
+    #[account(
+      seeds=[PREFIX.as_bytes(), COLLECTION_OVERRIDE.as_bytes(), args.creator_hash.as_ref()],
+      bump)]
+    creator_hash_override: UncheckedAccount<'info>,
+
+    /// CHECK: override keyed by the asset's verified collection, may not be initialized. Checked
+    /// against metadata_args in load_collection_override.
+    collection_override: UncheckedAccount<'info>,
+
+    /// CHECK: instructions sysvar, used to find the buyer's ed25519 signature of the intent and
+    /// the notary's of a fee_override.
+    #[account(address = sysvar::instructions::ID)]
+    instructions: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      has_one=buyer,
+      seeds=[PREFIX.as_bytes(), BUYER_ESCROW.as_bytes(), buyer.key().as_ref()],
+      bump=buyer_escrow.bump)]
+    buyer_escrow: Box<Account<'info, BuyerEscrow>>,
+
+    #[account(
+      init,
+      payer=relayer,
+      seeds=[
+          PREFIX.as_bytes(),
+          BUY_INTENT.as_bytes(),
+          buyer.key().as_ref(),
+          args.intent.nonce.to_le_bytes().as_ref(),
+      ],
+      space=BuyIntentReceipt::LEN,
+      bump)]
+    intent_receipt: Box<Account<'info, BuyIntentReceipt>>,
+
+    // Required with a fee_override the notary didn't co-sign, marks its nonce as used.
+    #[account(
+      init,
+      payer=relayer,
+      seeds=[
+          PREFIX.as_bytes(),
+          FEE_OVERRIDE.as_bytes(),
+          args.fee_override.as_ref().map_or(0, FeeOverride::nonce).to_le_bytes().as_ref(),
+      ],
+      space=FeeOverrideReceipt::LEN,
+      bump)]
+    fee_override_receipt: Option<Box<Account<'info, FeeOverrideReceipt>>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyNowWithIntent<'info>>,
+    args: BuyNowWithIntentArgs,
+) -> Result<u64> {
+    let relayer = &ctx.accounts.relayer.to_account_info();
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let payout_address = &ctx.accounts.payout_address.to_account_info();
+    let notary = &ctx.accounts.notary;
+    let platform_treasury = &ctx.accounts.platform_treasury;
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+    let system_program = &ctx.accounts.system_program;
+    let intent = &args.intent;
+
+    // Validate the intent against the buyer's signature and the listing being filled.
+    let message = [
+        crate::ID.as_ref(),
+        BUY_INTENT.as_bytes(),
+        &intent.try_to_vec()?,
+    ]
+    .concat();
+    verify_ed25519_ix(&ctx.accounts.instructions, buyer.key, &message)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if now > intent.expiry {
+        return Err(ErrorCode::OrderExpired.into());
+    }
+    if intent.asset_id != seller_trade_state.asset_id {
+        return Err(ErrorCode::AssetIDMismatch.into());
+    }
+
+    // Layaway buyers complete their purchase through buy_now.
+    if seller_trade_state.is_locked() {
+        return Err(ErrorCode::ListingLocked.into());
+    }
+    // Listings made before the seller bumped their nonce are cancelled.
+    if seller_trade_state.seller_nonce < UserState::current_nonce(&ctx.accounts.seller_user_state)?
+    {
+        return Err(ErrorCode::StaleNonce.into());
+    }
+    // Listings are frozen once the seller requested a cancel.
+    if seller_trade_state.cancel_requested_at != 0 {
+        return Err(ErrorCode::CancelPending.into());
+    }
+
+    seller_trade_state.apply_pending_price(now);
+    let buyer_price = seller_trade_state.buyer_price;
+    if buyer_price > intent.max_price {
+        msg!(
+            "Listing price {} exceeds max price {}",
+            buyer_price,
+            intent.max_price
+        );
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+
+    let intent_receipt = &mut ctx.accounts.intent_receipt;
+    intent_receipt.buyer = buyer.key();
+    intent_receipt.asset_id = intent.asset_id;
+    intent_receipt.buyer_price = buyer_price;
+    intent_receipt.nonce = intent.nonce;
+    intent_receipt.bump = ctx.bumps.intent_receipt;
+    intent_receipt.filled_at = now;
+    intent_receipt.relayer = relayer.key();
+    intent_receipt.expiry = intent.expiry;
+
+    // Seller proceeds, royalties and fees are debited from the buyer's escrow directly, the
+    // relayer only pays for the transaction and the intent receipt.
+    let buyer_escrow = &ctx.accounts.buyer_escrow.to_account_info();
+    let escrow_lamports_before = buyer_escrow.lamports();
+
+    // Create data_hash from metadata_hash + seller_fee_basis_points (secures creator royalties)
+    let data_hash = hash_metadata_data(args.metadata_hash, args.seller_fee_basis_points)?;
+
+    let fee_override_bp = match &args.fee_override {
+        Some(fee_override) if !notary.is_signer => Some(
+            fee_override.verify(
+                &ctx.accounts.instructions,
+                notary.key,
+                buyer.key,
+                &seller_trade_state.asset_id,
+                now,
+                ctx.accounts
+                    .fee_override_receipt
+                    .as_mut()
+                    .map(|receipt| &mut ***receipt),
+                ctx.bumps.fee_override_receipt,
+                relayer.key,
+            )?,
+        ),
+        _ if ctx.accounts.fee_override_receipt.is_some() => {
+            return Err(ErrorCode::InvalidFeeOverride.into())
+        }
+        _ => None,
+    };
+    let buyer_stats = &mut ctx.accounts.buyer_stats;
+    let seller_stats = &mut ctx.accounts.seller_stats;
+    buyer_stats.roll(now);
+    seller_stats.roll(now);
+    let config = Config::load(&ctx.accounts.config)?;
+    // 1-4: Pay Creator Fees, Seller and Treasury out of the buyer's escrow.
+    let payments = pay_for_listing(
+        &PurchaseAccounts {
+            payer: buyer_escrow,
+            buyer,
+            seller,
+            payout_address,
+            notary,
+            platform_treasury,
+            system_program,
+            creator_hash_override: &ctx.accounts.creator_hash_override,
+            collection_override: &ctx.accounts.collection_override,
+        },
+        PurchaseTerms {
+            buyer_price,
+            maker_fee_bp: args.maker_fee_bp,
+            taker_fee_bp: args.taker_fee_bp,
+            creator_shares: args.creator_shares,
+            creator_verified: args.creator_verified,
+            seller_fee_basis_points: args.seller_fee_basis_points,
+            data_hash,
+            creator_hash: args.creator_hash,
+            metadata_args: &args.metadata_args,
+            fee_override_bp,
+            tier_fee_bp: config
+                .tier_fee_bp(seller_stats.rolling_volume(), buyer_stats.rolling_volume()),
+        },
+        ctx.remaining_accounts,
+        &config,
+        &seller_trade_state.payment_mint,
+    )?;
+    let fees = &payments.fees;
+
+    // Buying your own listing doesn't count towards the fee tiers.
+    let volume = if buyer.key != seller.key {
+        buyer_price
+    } else {
+        0
+    };
+    buyer_stats.record_fill(buyer.key(), ctx.bumps.buyer_stats, now, volume);
+    seller_stats.record_fill(seller.key(), ctx.bumps.seller_stats, now, volume);
+
+    // 5. Transfer NFT to the intent's receiver
+    let bump = ctx.bumps.program_as_signer;
+    let seeds = &[PREFIX.as_bytes(), SIGNER.as_bytes(), &[bump][..]];
+    transfer_compressed_nft(
+        &ctx.accounts.tree_authority.to_account_info(),
+        // Transfer the NFT from the M3 escrow to the receiver.
+        &ctx.accounts.program_as_signer.to_account_info(),
+        &ctx.accounts.program_as_signer.to_account_info(), // delegate
+        &ctx.accounts.receiver.to_account_info(),
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        &ctx.accounts.system_program,
+        payments.proof_path,
+        ctx.accounts.bubblegum_program.key(),
+        args.root,
+        data_hash,
+        args.creator_hash, // This is secured by Bubblegum (important for paying creators)
+        args.nonce,
+        args.index,
+        Some(seeds),
+    )?;
+
+    let escrow_spent = settle_buyer_escrow(buyer_escrow, escrow_lamports_before)?;
+
+    msg!(
+        "{{\"price\":{},\"maker_fee\":{},\"taker_fee\":{},\"total_platform_fee\":{},\"fee_recipients\":[{}],\"escrow_spent\":{},\"relayer\":\"{}\"}}",
+        buyer_price,
+        fees.maker_fee,
+        fees.taker_fee,
+        fees.total_platform_fee,
+        payments.fee_recipients_log(),
+        escrow_spent,
+        relayer.key
+    );
+
+    Ok(buyer_price)
+}
@@ -0,0 +1,341 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::utils::get_asset_id;
+use solana_program::{program::invoke, system_instruction, sysvar};
+
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{
+        BubblegumProgram, Config, SignedOrderReceipt, TraderStats, TreeConfigAnchor, UserState,
+    },
+    utils::{
+        calculate_fees, check_metadata_args, hash_metadata_data, load_collection_override,
+        pay_creator_fees, pay_platform_fees, take_accounts, transfer_compressed_nft,
+        verified_collection, verify_creators, verify_ed25519_ix,
+    },
+};
+use anchor_lang::AnchorDeserialize;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+// The listing the seller signs off-chain. The signed message is the program id and SIGNED_ORDER
+// followed by the borsh serialized order, so it's only valid for this deployment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SignedOrder {
+    asset_id: Pubkey,
+    // Price of the NFT in the payment_mint.
+    buyer_price: u64,
+    // Unix timestamp after which the order can no longer be filled.
+    expiry: i64,
+    // Chosen by the seller, an order with the same nonce can only be filled once.
+    nonce: u64,
+    // The mint of the SPL token used to pay for the NFT, only the native mint is supported.
+    payment_mint: Pubkey,
+    // Seller's UserState nonce when signing, stale once the seller bumps it.
+    seller_nonce: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuySignedOrderArgs {
+    order: SignedOrder,
+
+    // Execute Sale Args
+    maker_fee_bp: i16,
+    taker_fee_bp: u16,
+    // Following arguments are required for paying creator royalties
+    creator_shares: Vec<u16>,
+    creator_verified: Vec<bool>,
+    // Creator royalties. Validated against the metadata_hash by Bubblegum after hashing with metadata_hash.
+    seller_fee_basis_points: u16,
+
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // The Keccak256 hash of the NFTs existing metadata (without the verified flag for the creator changed).
+    // Does not include the extra seller-fee-basis-points hash that's required by Bubblegum.
+    // The metadata is retrieved from off-chain data store.
+    metadata_hash: [u8; 32],
+    // The Keccak256 hash of the NFTs existing creators array (without the verified flag for the creator changed).
+    // The creators array is retrieved from off-chain data store.
+    creator_hash: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+    // Borsh serialized MetadataArgs, checked against metadata_hash and creator_hash. Used to find
+    // the override of the asset's verified collection.
+    metadata_args: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(args:BuySignedOrderArgs)]
+pub struct BuySignedOrder<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+    /// CHECK: seller is the signer of the order, checked against the ed25519 instruction.
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    /// CHECK: meNotary constant
+    #[account(address = ME_NOTARY)]
+    notary: UncheckedAccount<'info>,
+    /// CHECK: that this matches hard-coded auction_house_treasury
+    #[account(mut, address = ME_TREASURY)]
+    platform_treasury: UncheckedAccount<'info>,
+    /// CHECK: program config, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()], bump)]
+    config: UncheckedAccount<'info>,
+    /// CHECK: override keyed by the asset's creator hash, may not be initialized.
+    #[account(
+      seeds=[PREFIX.as_bytes(), COLLECTION_OVERRIDE.as_bytes(), args.creator_hash.as_ref()],
+      bump)]
+    creator_hash_override: UncheckedAccount<'info>,
+    /// CHECK: override keyed by the asset's verified collection, may not be initialized. Checked
+    /// against metadata_args in load_collection_override.
+    collection_override: UncheckedAccount<'info>,
+
+    // ==== cNFT transfer args ==== //
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        seeds::program = bubblegum_program.key(),
+        bump,
+      )]
+    /// CHECK: This account is neither written to nor read from.
+    pub tree_authority: Account<'info, TreeConfigAnchor>,
+    // The account that contains the Merkle tree, initialized by create_tree.
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    /// CHECK: program_as_signer, must be the leaf delegate set by the seller.
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+
+    #[account(
+      init,
+      payer=buyer,
+      seeds=[
+          PREFIX.as_bytes(),
+          SIGNED_ORDER.as_bytes(),
+          seller.key().as_ref(),
+          args.order.nonce.to_le_bytes().as_ref(),
+      ],
+      space=SignedOrderReceipt::LEN,
+      bump)]
+    order_receipt: Box<Account<'info, SignedOrderReceipt>>,
+
+    /// CHECK: only receives the asset.
+    receiver: UncheckedAccount<'info>,
+
+    /// CHECK: instructions sysvar, used to find the seller's ed25519 signature.
+    #[account(address = sysvar::instructions::ID)]
+    instructions: UncheckedAccount<'info>,
+
+    /// CHECK: seller's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), seller.key().as_ref()], bump)]
+    seller_user_state: UncheckedAccount<'info>,
+
+    #[account(
+      init_if_needed,
+      payer=buyer,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), buyer.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    buyer_stats: Box<Account<'info, TraderStats>>,
+
+    #[account(
+      init_if_needed,
+      payer=buyer,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), seller.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    seller_stats: Box<Account<'info, TraderStats>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuySignedOrder<'info>>,
+    args: BuySignedOrderArgs,
+) -> Result<()> {
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let notary = &ctx.accounts.notary;
+    let platform_treasury = &ctx.accounts.platform_treasury;
+    let system_program = &ctx.accounts.system_program;
+    let order = &args.order;
+
+    // Validate the order against the seller's signature and the asset being filled.
+    let message = [
+        crate::ID.as_ref(),
+        SIGNED_ORDER.as_bytes(),
+        &order.try_to_vec()?,
+    ]
+    .concat();
+    verify_ed25519_ix(&ctx.accounts.instructions, seller.key, &message)?;
+
+    if Clock::get()?.unix_timestamp > order.expiry {
+        return Err(ErrorCode::OrderExpired.into());
+    }
+    if order.payment_mint != spl_token::native_mint::ID {
+        return Err(ErrorCode::InvalidPaymentMint.into());
+    }
+    if order.buyer_price > MAX_PRICE || order.buyer_price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if order.seller_nonce < UserState::current_nonce(&ctx.accounts.seller_user_state)? {
+        return Err(ErrorCode::StaleNonce.into());
+    }
+    let asset_id = get_asset_id(&ctx.accounts.merkle_tree.key(), args.nonce);
+    if asset_id != order.asset_id {
+        return Err(ErrorCode::AssetIDMismatch.into());
+    }
+
+    let order_receipt = &mut ctx.accounts.order_receipt;
+    order_receipt.seller = seller.key();
+    order_receipt.asset_id = asset_id;
+    order_receipt.buyer_price = order.buyer_price;
+    order_receipt.nonce = order.nonce;
+    order_receipt.bump = ctx.bumps.order_receipt;
+    order_receipt.filled_at = Clock::get()?.unix_timestamp;
+    order_receipt.buyer = buyer.key();
+    order_receipt.expiry = order.expiry;
+
+    // Remaining accounts are 1. (Optional) creator addresses, 2. a (RoyaltyRedirect, payee) pair
+    // per creator, 3. fee recipient accounts and 4. Merkle proof path.
+    let creator_shares_length = args.creator_shares.len();
+    let creator_shares_clone = args.creator_shares.clone();
+    let config = Config::load(&ctx.accounts.config)?;
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let creator_accounts = take_accounts(&mut remaining_accounts, creator_shares_length)?;
+    let royalty_redirects = take_accounts(&mut remaining_accounts, 2 * creator_shares_length)?;
+    let fee_recipient_accounts =
+        take_accounts(&mut remaining_accounts, config.fee_recipients.len())?;
+    let proof_path = remaining_accounts;
+
+    // Create data_hash from metadata_hash + seller_fee_basis_points (secures creator royalties)
+    let data_hash = hash_metadata_data(args.metadata_hash, args.seller_fee_basis_points)?;
+
+    // The asset's override replaces the default royalty share and the fee tiers.
+    let metadata = check_metadata_args(&args.metadata_args, data_hash, args.creator_hash)?;
+    let collection_override = load_collection_override(
+        &ctx.accounts.creator_hash_override,
+        args.creator_hash,
+        &ctx.accounts.collection_override,
+        verified_collection(&metadata),
+    )?;
+
+    // 1: Pay Creator Fees
+    verify_creators(
+        creator_accounts.iter(),
+        args.creator_shares,
+        args.creator_verified,
+        args.creator_hash,
+    )?;
+    pay_creator_fees(
+        &mut creator_accounts.iter(),
+        creator_shares_clone,
+        royalty_redirects,
+        &buyer.to_account_info(),
+        system_program,
+        order.buyer_price,
+        collection_override.as_ref(),
+        args.seller_fee_basis_points,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.buyer_stats.roll(now);
+    ctx.accounts.seller_stats.roll(now);
+    let tier_fee_bp = config.tier_fee_bp(
+        ctx.accounts.seller_stats.rolling_volume(),
+        ctx.accounts.buyer_stats.rolling_volume(),
+    );
+
+    // 2: Calculate fees
+    let fees = calculate_fees(
+        notary,
+        order.buyer_price,
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        buyer,
+        seller,
+        tier_fee_bp,
+        collection_override.as_ref(),
+        config.fee_cap(&order.payment_mint),
+    )?;
+
+    // 3: Buyer pays Seller
+    invoke(
+        &system_instruction::transfer(&buyer.key(), seller.key, fees.seller_will_get_from_buyer),
+        &[
+            buyer.to_account_info(),
+            seller.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    // 4. Buyer pays Treasury, split between the configured fee recipients.
+    pay_platform_fees(
+        buyer,
+        &platform_treasury.to_account_info(),
+        fee_recipient_accounts,
+        &config.fee_recipients,
+        system_program,
+        fees.total_platform_fee,
+    )?;
+
+    // 5. Transfer NFT to Buyer, program_as_signer acts as the seller's leaf delegate.
+    let bump = ctx.bumps.program_as_signer;
+    let seeds = &[PREFIX.as_bytes(), SIGNER.as_bytes(), &[bump][..]];
+    transfer_compressed_nft(
+        &ctx.accounts.tree_authority.to_account_info(),
+        seller,
+        &ctx.accounts.program_as_signer.to_account_info(), // delegate
+        &ctx.accounts.receiver.to_account_info(),
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        &ctx.accounts.system_program,
+        proof_path,
+        ctx.accounts.bubblegum_program.key(),
+        args.root,
+        data_hash,
+        args.creator_hash, // This is secured by Bubblegum (important for paying creators)
+        args.nonce,
+        args.index,
+        Some(seeds),
+    )?;
+
+    // Trading against yourself doesn't count towards the fee tiers.
+    let volume = if buyer.key() != seller.key() {
+        order.buyer_price
+    } else {
+        0
+    };
+    let buyer_bump = ctx.bumps.buyer_stats;
+    ctx.accounts
+        .buyer_stats
+        .record_fill(buyer.key(), buyer_bump, now, volume);
+    let seller_bump = ctx.bumps.seller_stats;
+    ctx.accounts
+        .seller_stats
+        .record_fill(seller.key(), seller_bump, now, volume);
+
+    msg!(
+        "{{\"price\":{},\"maker_fee\":{},\"taker_fee\":{},\"total_platform_fee\":{}}}",
+        order.buyer_price,
+        fees.maker_fee,
+        fees.taker_fee,
+        fees.total_platform_fee
+    );
+
+    Ok(())
+}
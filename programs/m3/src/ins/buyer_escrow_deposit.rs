@@ -0,0 +1,46 @@
+use crate::{constants::*, states::BuyerEscrow};
+use anchor_lang::prelude::*;
+use solana_program::{program::invoke, system_instruction};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuyerEscrowDepositArgs {
+    amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct BuyerEscrowDeposit<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+
+    #[account(
+      init_if_needed,
+      payer=buyer,
+      seeds=[PREFIX.as_bytes(), BUYER_ESCROW.as_bytes(), buyer.key().as_ref()],
+      space=BuyerEscrow::LEN,
+      bump)]
+    buyer_escrow: Box<Account<'info, BuyerEscrow>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<BuyerEscrowDeposit>, args: BuyerEscrowDepositArgs) -> Result<()> {
+    let buyer = &ctx.accounts.buyer;
+    let buyer_escrow = &mut ctx.accounts.buyer_escrow;
+
+    if buyer_escrow.created_at == 0 {
+        buyer_escrow.buyer = buyer.key();
+        buyer_escrow.bump = ctx.bumps.buyer_escrow;
+        buyer_escrow.created_at = Clock::get()?.unix_timestamp;
+    }
+
+    invoke(
+        &system_instruction::transfer(buyer.key, &buyer_escrow.key(), args.amount),
+        &[
+            buyer.to_account_info(),
+            buyer_escrow.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::{constants::*, errors::ErrorCode, states::BuyerEscrow};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuyerEscrowWithdrawArgs {
+    amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct BuyerEscrowWithdraw<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=buyer,
+      seeds=[PREFIX.as_bytes(), BUYER_ESCROW.as_bytes(), buyer.key().as_ref()],
+      bump=buyer_escrow.bump)]
+    buyer_escrow: Box<Account<'info, BuyerEscrow>>,
+}
+
+pub fn handle(ctx: Context<BuyerEscrowWithdraw>, args: BuyerEscrowWithdrawArgs) -> Result<()> {
+    let buyer_escrow = &ctx.accounts.buyer_escrow.to_account_info();
+    let buyer = &ctx.accounts.buyer.to_account_info();
+
+    // The escrow stays rent exempt so later deposits don't need to recreate it.
+    let available = buyer_escrow
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(BuyerEscrow::LEN));
+    if args.amount > available {
+        return Err(ErrorCode::InsufficientEscrowBalance.into());
+    }
+
+    **buyer_escrow.try_borrow_mut_lamports()? -= args.amount;
+    **buyer.try_borrow_mut_lamports()? += args.amount;
+
+    Ok(())
+}
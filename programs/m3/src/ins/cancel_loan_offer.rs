@@ -0,0 +1,25 @@
+use crate::{constants::*, states::LoanOffer};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelLoanOffer<'info> {
+    #[account(mut)]
+    lender: Signer<'info>,
+
+    #[account(
+      mut,
+      close=lender,
+      has_one=lender,
+      seeds=[
+          PREFIX.as_bytes(),
+          LOAN_OFFER.as_bytes(),
+          lender.key().as_ref(),
+          loan_offer.offer_id.as_ref(),
+      ],
+      bump=loan_offer.bump)]
+    loan_offer: Box<Account<'info, LoanOffer>>,
+}
+
+pub fn handle(_ctx: Context<CancelLoanOffer>) -> Result<()> {
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::{
     constants::*,
     errors::ErrorCode,
-    states::{BubblegumProgram, SellerTradeState, TreeConfigAnchor},
+    states::{BubblegumProgram, Config, SellerTradeState, TreeConfigAnchor, UserState},
     utils::transfer_compressed_nft,
 };
 use anchor_lang::{prelude::*, AnchorDeserialize};
@@ -68,7 +68,7 @@ pub struct CancelSell<'info> {
 
     #[account(
       mut,
-      close=wallet, // Close account after this instruction
+      close=rent_payer, // Close account after this instruction
       seeds=[
           PREFIX.as_bytes(),
           merkle_tree.key().as_ref(),
@@ -76,6 +76,18 @@ pub struct CancelSell<'info> {
       ],
       bump=seller_trade_state.bump)]
     seller_trade_state: Box<Account<'info, SellerTradeState>>,
+
+    /// CHECK: seller's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), wallet.key().as_ref()], bump)]
+    seller_user_state: UncheckedAccount<'info>,
+
+    /// CHECK: gets the seller_trade_state rent back, checked against seller_trade_state.
+    #[account(mut, address = seller_trade_state.rent_payer())]
+    rent_payer: UncheckedAccount<'info>,
+
+    /// CHECK: program config, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()], bump)]
+    config: UncheckedAccount<'info>,
 }
 
 pub fn handle<'info>(
@@ -91,14 +103,32 @@ pub fn handle<'info>(
     if seller_trade_state.seller != *wallet.key {
         return Err(ErrorCode::IncorrectOwner.into());
     }
+    // The layaway deposit has to be settled first, closing would hand it to the rent_payer.
+    if seller_trade_state.is_locked() {
+        return Err(ErrorCode::ListingLocked.into());
+    }
 
     // Cancel Authority must sign, or Notary and seller must sign.
+    // Listings invalidated by a nonce bump can be returned to the seller by anyone.
+    // The seller can also cancel alone once the cooldown of a cancel request has elapsed.
     let cancel_authority_signed = notary.is_signer && (*notary.key == CANCEL_AUTHORITY);
     let notary_signed = notary.is_signer && (*notary.key == ME_NOTARY);
-    let valid_cancel = cancel_authority_signed || (wallet.is_signer && notary_signed);
+    let is_stale = seller_trade_state.seller_nonce
+        < UserState::current_nonce(&ctx.accounts.seller_user_state)?;
+    let cancel_requested = seller_trade_state.cancel_requested_at != 0;
+    let valid_cancel = cancel_authority_signed
+        || (wallet.is_signer && (notary_signed || cancel_requested))
+        || is_stale;
     if !valid_cancel {
         return Err(ErrorCode::NoValidSignerPresent.into());
     }
+    if !(cancel_authority_signed || notary_signed || is_stale)
+        && Clock::get()?.unix_timestamp
+            < seller_trade_state.cancel_requested_at
+                + Config::load(&ctx.accounts.config)?.seller_cancel_cooldown_seconds
+    {
+        return Err(ErrorCode::CancelCooldownNotElapsed.into());
+    }
 
     // Seller trade state should not be empty
     if seller_trade_state.to_account_info().data_is_empty() {
@@ -0,0 +1,142 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, SwapOrder, UserState},
+    utils::{take_accounts, transfer_compressed_nft},
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use mpl_bubblegum::utils::get_asset_id;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CancelSwapItemArgs {
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // The Keccak256 hash of the NFTs existing metadata (without the verified flag for the creator changed).
+    // The metadata is retrieved from off-chain data store.
+    data_hash: [u8; 32],
+    // The Keccak256 hash of the NFTs existing creators array (without the verified flag for the creator changed).
+    // The creators array is retrieved from off-chain data store.
+    creator_hash: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // Number of proof accounts for this asset in remaining_accounts.
+    proof_len: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CancelSwapArgs {
+    // In the same order as the swap_order offered items.
+    offered: Vec<CancelSwapItemArgs>,
+}
+
+#[derive(Accounts)]
+pub struct CancelSwap<'info> {
+    #[account(mut)]
+    /// CHECK: that this matches the maker in the swap_order.
+    maker: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(
+      seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()],
+      bump)]
+    program_as_signer: UncheckedAccount<'info>, // escrow that currently owns the cNFTs
+
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    /// CHECK: Notary or cancel authority must sign. Explicit address checked in the handler.
+    notary: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      close=maker, // Returns the escrowed lamports together with the rent
+      seeds=[
+          PREFIX.as_bytes(),
+          SWAP.as_bytes(),
+          maker.key().as_ref(),
+          swap_order.swap_id.as_ref(),
+      ],
+      bump=swap_order.bump)]
+    swap_order: Box<Account<'info, SwapOrder>>,
+
+    /// CHECK: maker's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), maker.key().as_ref()], bump)]
+    maker_user_state: UncheckedAccount<'info>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelSwap<'info>>,
+    args: CancelSwapArgs,
+) -> Result<()> {
+    let maker = &ctx.accounts.maker;
+    let swap_order = &ctx.accounts.swap_order;
+    let notary = &ctx.accounts.notary;
+
+    if swap_order.maker != *maker.key {
+        return Err(ErrorCode::IncorrectOwner.into());
+    }
+
+    // Same rules as cancel_sell: Cancel Authority must sign, or Notary and maker must sign.
+    // Swaps invalidated by a nonce bump can be returned to the maker by anyone.
+    let cancel_authority_signed = notary.is_signer && (*notary.key == CANCEL_AUTHORITY);
+    let notary_signed = notary.is_signer && (*notary.key == ME_NOTARY);
+    let is_stale =
+        swap_order.maker_nonce < UserState::current_nonce(&ctx.accounts.maker_user_state)?;
+    let valid_cancel = cancel_authority_signed || (maker.is_signer && notary_signed) || is_stale;
+    if !valid_cancel {
+        return Err(ErrorCode::NoValidSignerPresent.into());
+    }
+
+    if args.offered.len() != swap_order.offered.len() {
+        return Err(ErrorCode::InvalidSwapItems.into());
+    }
+
+    let bump = ctx.bumps.program_as_signer;
+    let seeds = &[PREFIX.as_bytes(), SIGNER.as_bytes(), &[bump][..]];
+
+    // Remaining accounts are, for each offered item: tree_authority, merkle_tree and the Merkle
+    // proof path.
+    let mut remaining_accounts = ctx.remaining_accounts;
+    for (item, item_args) in swap_order.offered.iter().zip(args.offered.iter()) {
+        let tree_accounts = take_accounts(&mut remaining_accounts, 2)?;
+        let proof_path = take_accounts(&mut remaining_accounts, item_args.proof_len as usize)?;
+        let (tree_authority, merkle_tree) = (&tree_accounts[0], &tree_accounts[1]);
+
+        if merkle_tree.key() != item.merkle_tree
+            || get_asset_id(merkle_tree.key, item_args.nonce) != item.asset_id
+        {
+            return Err(ErrorCode::AssetIDMismatch.into());
+        }
+
+        transfer_compressed_nft(
+            tree_authority,
+            &ctx.accounts.program_as_signer.to_account_info(),
+            &ctx.accounts.program_as_signer.to_account_info(), // delegate
+            &maker.to_account_info(),
+            merkle_tree,
+            &ctx.accounts.log_wrapper,
+            &ctx.accounts.compression_program,
+            &ctx.accounts.system_program,
+            proof_path,
+            ctx.accounts.bubblegum_program.key(),
+            item_args.root,
+            item_args.data_hash,
+            item_args.creator_hash,
+            item_args.nonce,
+            item.index,
+            Some(seeds),
+        )?;
+    }
+
+    Ok(())
+}
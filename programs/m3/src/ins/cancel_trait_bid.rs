@@ -0,0 +1,25 @@
+use crate::{constants::*, states::TraitBid};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelTraitBid<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+
+    #[account(
+      mut,
+      close=buyer,
+      has_one=buyer,
+      seeds=[
+          PREFIX.as_bytes(),
+          TRAIT_BID.as_bytes(),
+          buyer.key().as_ref(),
+          trait_bid.bid_id.as_ref(),
+      ],
+      bump=trait_bid.bump)]
+    trait_bid: Box<Account<'info, TraitBid>>,
+}
+
+pub fn handle(_ctx: Context<CancelTraitBid>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::{constants::*, errors::ErrorCode, states::BuyIntentReceipt};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseBuyIntentReceipt<'info> {
+    /// CHECK: relayer checked in intent_receipt, gets the rent back.
+    #[account(mut)]
+    relayer: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      close=relayer,
+      has_one=relayer,
+      seeds=[
+          PREFIX.as_bytes(),
+          BUY_INTENT.as_bytes(),
+          intent_receipt.buyer.as_ref(),
+          intent_receipt.nonce.to_le_bytes().as_ref(),
+      ],
+      bump=intent_receipt.bump)]
+    intent_receipt: Box<Account<'info, BuyIntentReceipt>>,
+}
+
+// Anyone can close the receipt once the intent expired, the intent can't be used again past its
+// expiry so the receipt is no longer needed to block a replay.
+pub fn handle(ctx: Context<CloseBuyIntentReceipt>) -> Result<()> {
+    if Clock::get()?.unix_timestamp <= ctx.accounts.intent_receipt.expiry {
+        return Err(ErrorCode::OrderNotExpired.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::{constants::*, errors::ErrorCode, states::FeeOverrideReceipt};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseFeeOverrideReceipt<'info> {
+    /// CHECK: rent_payer checked in fee_override_receipt, gets the rent back.
+    #[account(mut)]
+    rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      close=rent_payer,
+      has_one=rent_payer,
+      seeds=[
+          PREFIX.as_bytes(),
+          FEE_OVERRIDE.as_bytes(),
+          fee_override_receipt.nonce.to_le_bytes().as_ref(),
+      ],
+      bump=fee_override_receipt.bump)]
+    fee_override_receipt: Box<Account<'info, FeeOverrideReceipt>>,
+}
+
+// Anyone can close the receipt once the override expired, it can't be used again past its
+// expiry so the receipt is no longer needed to block a replay.
+pub fn handle(ctx: Context<CloseFeeOverrideReceipt>) -> Result<()> {
+    if Clock::get()?.unix_timestamp <= ctx.accounts.fee_override_receipt.expiry {
+        return Err(ErrorCode::OrderNotExpired.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::{constants::*, errors::ErrorCode, states::Pool};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClosePool<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=owner,
+      close=owner,
+      constraint= pool.nft_count == 0 @ ErrorCode::PoolNotEmpty,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL.as_bytes(),
+          owner.key().as_ref(),
+          pool.uuid.as_ref(),
+      ],
+      bump=pool.bump)]
+    pool: Box<Account<'info, Pool>>,
+}
+
+// Closing returns the remaining pool balance and rent to the owner.
+pub fn handle(_ctx: Context<ClosePool>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, RentalListing, TreeConfigAnchor},
+    utils::transfer_compressed_nft,
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CloseRentalArgs {
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+}
+
+#[derive(Accounts)]
+pub struct CloseRental<'info> {
+    /// CHECK: owner checked in rental_listing, gets the asset and the rent back.
+    #[account(mut)]
+    owner: UncheckedAccount<'info>,
+
+    // ==== cNFT transfer args ==== //
+    #[account(
+      mut,
+      seeds = [merkle_tree.key().as_ref()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+    )]
+    /// CHECK: This account is neither written to nor read from.
+    pub tree_authority: Account<'info, TreeConfigAnchor>,
+    // The account that contains the Merkle tree, initialized by create_tree.
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut, address = rental_listing.merkle_tree)]
+    merkle_tree: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    #[account(
+      mut,
+      close=owner,
+      has_one=owner,
+      seeds=[
+          PREFIX.as_bytes(),
+          RENTAL.as_bytes(),
+          rental_listing.merkle_tree.as_ref(),
+          rental_listing.index.to_le_bytes().as_ref(),
+      ],
+      bump=rental_listing.bump)]
+    rental_listing: Box<Account<'info, RentalListing>>, // escrow that currently owns the cNFT
+}
+
+// Returns the asset to the owner. Anyone can crank this once a rental ended, the owner can also
+// delist an asset that was never rented. Remaining accounts are the Merkle proof path.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseRental<'info>>,
+    args: CloseRentalArgs,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let rental_listing = &ctx.accounts.rental_listing;
+
+    let was_rented = rental_listing.renter != Pubkey::default();
+    if rental_listing.is_rented(Clock::get()?.unix_timestamp) {
+        return Err(ErrorCode::AssetRented.into());
+    }
+    if !was_rented && !owner.is_signer {
+        return Err(ErrorCode::NoValidSignerPresent.into());
+    }
+
+    let merkle_tree = rental_listing.merkle_tree;
+    let index = rental_listing.index.to_le_bytes();
+    let seeds = &[
+        PREFIX.as_bytes(),
+        RENTAL.as_bytes(),
+        merkle_tree.as_ref(),
+        index.as_ref(),
+        &[rental_listing.bump][..],
+    ];
+    transfer_compressed_nft(
+        &ctx.accounts.tree_authority.to_account_info(),
+        &rental_listing.to_account_info(),
+        &rental_listing.to_account_info(), // delegate
+        &owner.to_account_info(),
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        ctx.accounts.bubblegum_program.key(),
+        args.root,
+        rental_listing.data_hash,
+        rental_listing.creator_hash,
+        rental_listing.nonce,
+        rental_listing.index,
+        Some(seeds),
+    )?;
+
+    Ok(())
+}
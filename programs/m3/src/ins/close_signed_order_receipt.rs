@@ -0,0 +1,31 @@
+use crate::{constants::*, errors::ErrorCode, states::SignedOrderReceipt};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseSignedOrderReceipt<'info> {
+    /// CHECK: buyer checked in order_receipt, gets the rent back.
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      close=buyer,
+      has_one=buyer,
+      seeds=[
+          PREFIX.as_bytes(),
+          SIGNED_ORDER.as_bytes(),
+          order_receipt.seller.as_ref(),
+          order_receipt.nonce.to_le_bytes().as_ref(),
+      ],
+      bump=order_receipt.bump)]
+    order_receipt: Box<Account<'info, SignedOrderReceipt>>,
+}
+
+// Anyone can close the receipt once the order expired, the signed order can't be filled again
+// past its expiry so the receipt is no longer needed to block a replay.
+pub fn handle(ctx: Context<CloseSignedOrderReceipt>) -> Result<()> {
+    if Clock::get()?.unix_timestamp <= ctx.accounts.order_receipt.expiry {
+        return Err(ErrorCode::OrderNotExpired.into());
+    }
+    Ok(())
+}
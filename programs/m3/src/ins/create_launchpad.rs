@@ -0,0 +1,132 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, Launchpad, LaunchpadPhase, TreeConfigAnchor},
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use mpl_bubblegum::types::MetadataArgs;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateLaunchpadArgs {
+    launchpad_id: Pubkey,
+    price: u64,
+    supply: u32,
+    start_at: i64,
+    // Borsh serialized Bubblegum MetadataArgs shared by every mint. The name gets " #<n>" and the
+    // uri "<n>.json" appended, the collection is set to collection_mint.
+    metadata_template: Vec<u8>,
+    // Optional staged sale, e.g. an allowlist phase followed by a public one. Replaces price and
+    // start_at when not empty.
+    phases: Vec<LaunchpadPhase>,
+}
+
+#[derive(Accounts)]
+#[instruction(args:CreateLaunchpadArgs)]
+pub struct CreateLaunchpad<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(
+      init,
+      payer=creator,
+      seeds=[
+          PREFIX.as_bytes(),
+          LAUNCHPAD.as_bytes(),
+          creator.key().as_ref(),
+          args.launchpad_id.as_ref(),
+      ],
+      space=Launchpad::LEN,
+      bump)]
+    launchpad: Box<Account<'info, Launchpad>>,
+
+    /// CHECK: program_as_signer
+    #[account(
+      seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()],
+      bump)]
+    program_as_signer: UncheckedAccount<'info>,
+
+    // The creator's tree has to be delegated to program_as_signer so mint_buy can mint into it.
+    #[account(
+      seeds = [merkle_tree.key().as_ref()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+      constraint = tree_authority.tree_creator == creator.key() @ ErrorCode::InvalidLaunchpad,
+      constraint = tree_authority.tree_delegate == program_as_signer.key() @ ErrorCode::InvalidLaunchpad,
+    )]
+    tree_authority: Account<'info, TreeConfigAnchor>,
+    /// CHECK: tree_authority is derived from it
+    merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: the collection authority record for program_as_signer is checked by Token Metadata
+    /// when minting.
+    collection_mint: UncheckedAccount<'info>,
+    /// CHECK: the collection's Token Metadata account, its update authority has to be the creator.
+    /// Checked in the handler.
+    #[account(
+      owner = TOKEN_METADATA_PROGRAM,
+      seeds = [
+          METADATA.as_bytes(),
+          TOKEN_METADATA_PROGRAM.as_ref(),
+          collection_mint.key().as_ref(),
+      ],
+      seeds::program = TOKEN_METADATA_PROGRAM,
+      bump,
+    )]
+    collection_metadata: UncheckedAccount<'info>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<CreateLaunchpad>, args: CreateLaunchpadArgs) -> Result<()> {
+    if args.price > MAX_PRICE {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.phases.len() > MAX_LAUNCHPAD_PHASES
+        || args
+            .phases
+            .iter()
+            .any(|phase| phase.price > MAX_PRICE || phase.start_at >= phase.end_at)
+    {
+        return Err(ErrorCode::InvalidLaunchpad.into());
+    }
+    let tree_authority = &ctx.accounts.tree_authority;
+    let remaining_capacity = tree_authority
+        .total_mint_capacity
+        .saturating_sub(tree_authority.num_minted);
+    if args.supply == 0 || args.supply as u64 > remaining_capacity {
+        return Err(ErrorCode::InvalidLaunchpad.into());
+    }
+    if args.metadata_template.len() > MAX_METADATA_TEMPLATE_LEN {
+        return Err(ErrorCode::InvalidMetadataArgs.into());
+    }
+    let template = MetadataArgs::try_from_slice(&args.metadata_template)
+        .map_err(|_| ErrorCode::InvalidMetadataArgs)?;
+    if template.seller_fee_basis_points > 10000 {
+        return Err(ErrorCode::InvalidMetadataArgs.into());
+    }
+    // program_as_signer is the collection authority of every launchpad, only the collection's
+    // update authority may mint into it. Metadata layout: key(1), update_authority(32), mint(32).
+    let collection_metadata = ctx.accounts.collection_metadata.try_borrow_data()?;
+    if collection_metadata.len() < 65
+        || collection_metadata[0] != METADATA_V1_KEY
+        || collection_metadata[1..33] != ctx.accounts.creator.key().to_bytes()
+    {
+        return Err(ErrorCode::InvalidLaunchpad.into());
+    }
+
+    let launchpad = &mut ctx.accounts.launchpad;
+    launchpad.creator = ctx.accounts.creator.key();
+    launchpad.launchpad_id = args.launchpad_id;
+    launchpad.bump = ctx.bumps.launchpad;
+    launchpad.merkle_tree = ctx.accounts.merkle_tree.key();
+    launchpad.collection_mint = ctx.accounts.collection_mint.key();
+    launchpad.price = args.price;
+    launchpad.supply = args.supply;
+    launchpad.minted = 0;
+    launchpad.start_at = args.start_at;
+    launchpad.metadata_template = args.metadata_template;
+    launchpad.phases = args.phases;
+
+    Ok(())
+}
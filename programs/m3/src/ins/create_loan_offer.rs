@@ -0,0 +1,69 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{LoanOffer, UserState},
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateLoanOfferArgs {
+    offer_id: Pubkey,
+    // Verified collection the collateral has to belong to.
+    collection: Pubkey,
+    principal: u64,
+    // Flat interest on the principal for the whole duration.
+    interest_bp: u16,
+    duration_seconds: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(args:CreateLoanOfferArgs)]
+pub struct CreateLoanOffer<'info> {
+    #[account(mut)]
+    lender: Signer<'info>,
+
+    #[account(
+      init,
+      payer=lender,
+      seeds=[
+          PREFIX.as_bytes(),
+          LOAN_OFFER.as_bytes(),
+          lender.key().as_ref(),
+          args.offer_id.as_ref(),
+      ],
+      space=LoanOffer::LEN,
+      bump)]
+    loan_offer: Box<Account<'info, LoanOffer>>,
+
+    /// CHECK: lender's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), lender.key().as_ref()], bump)]
+    lender_user_state: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+// Nothing is locked here, the principal is drawn from the lender's buyer escrow when the loan is
+// taken.
+pub fn handle(ctx: Context<CreateLoanOffer>, args: CreateLoanOfferArgs) -> Result<()> {
+    let lender = &ctx.accounts.lender;
+
+    if args.principal > MAX_PRICE || args.principal == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.duration_seconds <= 0 || args.interest_bp > MAX_LOAN_INTEREST_BP {
+        return Err(ErrorCode::InvalidLoanOffer.into());
+    }
+
+    let loan_offer = &mut ctx.accounts.loan_offer;
+    loan_offer.lender = lender.key();
+    loan_offer.offer_id = args.offer_id;
+    loan_offer.bump = ctx.bumps.loan_offer;
+    loan_offer.lender_nonce = UserState::current_nonce(&ctx.accounts.lender_user_state)?;
+    loan_offer.collection = args.collection;
+    loan_offer.principal = args.principal;
+    loan_offer.interest_bp = args.interest_bp;
+    loan_offer.duration_seconds = args.duration_seconds;
+    loan_offer.created_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::{
+    constants::*,
+    states::{CurveType, Pool},
+    utils::assert_valid_pool_params,
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreatePoolArgs {
+    // Random key so an owner can have several pools.
+    uuid: Pubkey,
+    // Verified collection of the cNFTs the pool trades.
+    collection: Pubkey,
+    curve_type: CurveType,
+    // Price the pool sells its next cNFT for.
+    spot_price: u64,
+    // How much the spot price moves after each trade, lamports or bp depending on curve_type.
+    delta: u64,
+    // Fee kept by the pool on every trade.
+    lp_fee_bp: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(args:CreatePoolArgs)]
+pub struct CreatePool<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(
+      init,
+      payer=owner,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL.as_bytes(),
+          owner.key().as_ref(),
+          args.uuid.as_ref(),
+      ],
+      space=Pool::LEN,
+      bump)]
+    pool: Box<Account<'info, Pool>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<CreatePool>, args: CreatePoolArgs) -> Result<()> {
+    assert_valid_pool_params(args.curve_type, args.spot_price, args.delta, args.lp_fee_bp)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.owner = ctx.accounts.owner.key();
+    pool.uuid = args.uuid;
+    pool.collection = args.collection;
+    pool.curve_type = args.curve_type;
+    pool.spot_price = args.spot_price;
+    pool.delta = args.delta;
+    pool.lp_fee_bp = args.lp_fee_bp;
+    pool.nft_count = 0;
+    pool.bump = ctx.bumps.pool;
+    pool.created_at = Clock::get()?.unix_timestamp;
+    pool.updated_at = pool.created_at;
+
+    Ok(())
+}
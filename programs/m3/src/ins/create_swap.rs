@@ -0,0 +1,161 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, BundleItem, SwapOrder, SwapWant, UserState},
+    utils::{take_accounts, transfer_compressed_nft},
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use mpl_bubblegum::utils::get_asset_id;
+use solana_program::{program::invoke, system_instruction};
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSwapItemArgs {
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // The Keccak256 hash of the NFTs existing metadata (without the verified flag for the creator changed).
+    // The metadata is retrieved from off-chain data store.
+    data_hash: [u8; 32],
+    // The Keccak256 hash of the NFTs existing creators array (without the verified flag for the creator changed).
+    // The creators array is retrieved from off-chain data store.
+    creator_hash: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+    // Number of proof accounts for this asset in remaining_accounts.
+    proof_len: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSwapArgs {
+    // Random key so a maker can have several swaps.
+    swap_id: Pubkey,
+    // Lamports the taker gets on top of the offered cNFTs.
+    lamports: u64,
+    // cNFTs the maker escrows.
+    offered: Vec<CreateSwapItemArgs>,
+    // cNFTs the maker wants in exchange, by asset_id or collection.
+    wanted: Vec<SwapWant>,
+}
+
+#[derive(Accounts)]
+#[instruction(args:CreateSwapArgs)]
+pub struct CreateSwap<'info> {
+    #[account(mut)]
+    maker: Signer<'info>,
+    /// CHECK: program_as_signer
+    #[account(
+      seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()],
+      bump)]
+    program_as_signer: UncheckedAccount<'info>, // escrow to hold the ownership of the cnfts
+
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    #[account(
+      init,
+      payer=maker,
+      seeds=[
+          PREFIX.as_bytes(),
+          SWAP.as_bytes(),
+          maker.key().as_ref(),
+          args.swap_id.as_ref(),
+      ],
+      space=SwapOrder::LEN,
+      bump)]
+    swap_order: Box<Account<'info, SwapOrder>>,
+
+    /// CHECK: maker's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), maker.key().as_ref()], bump)]
+    maker_user_state: UncheckedAccount<'info>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateSwap<'info>>,
+    args: CreateSwapArgs,
+) -> Result<()> {
+    let maker = &ctx.accounts.maker;
+    let swap_order = &ctx.accounts.swap_order;
+
+    if args.offered.is_empty()
+        || args.offered.len() > MAX_SWAP_ITEMS
+        || args.wanted.is_empty()
+        || args.wanted.len() > MAX_SWAP_ITEMS
+    {
+        return Err(ErrorCode::InvalidSwapItems.into());
+    }
+    if args.lamports > MAX_PRICE {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+
+    // Remaining accounts are, for each offered item: tree_authority, merkle_tree, leaf_delegate
+    // and the Merkle proof path.
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let mut offered = Vec::with_capacity(args.offered.len());
+    for item in args.offered.iter() {
+        let item_accounts = take_accounts(&mut remaining_accounts, 3)?;
+        let proof_path = take_accounts(&mut remaining_accounts, item.proof_len as usize)?;
+        let (tree_authority, merkle_tree, leaf_delegate) =
+            (&item_accounts[0], &item_accounts[1], &item_accounts[2]);
+
+        transfer_compressed_nft(
+            tree_authority,
+            &maker.to_account_info(),
+            leaf_delegate,
+            &ctx.accounts.program_as_signer.to_account_info(),
+            merkle_tree,
+            &ctx.accounts.log_wrapper,
+            &ctx.accounts.compression_program,
+            &ctx.accounts.system_program,
+            proof_path,
+            ctx.accounts.bubblegum_program.key(),
+            item.root,
+            item.data_hash,
+            item.creator_hash,
+            item.nonce,
+            item.index,
+            None, // signer passed through from ctx
+        )?;
+
+        offered.push(BundleItem {
+            merkle_tree: merkle_tree.key(),
+            index: item.index,
+            asset_id: get_asset_id(merkle_tree.key, item.nonce),
+        });
+    }
+
+    // Escrow the lamports in the swap_order account.
+    if args.lamports > 0 {
+        invoke(
+            &system_instruction::transfer(maker.key, &swap_order.key(), args.lamports),
+            &[
+                maker.to_account_info(),
+                swap_order.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    let swap_order = &mut ctx.accounts.swap_order;
+    swap_order.maker = maker.key();
+    swap_order.swap_id = args.swap_id;
+    swap_order.bump = ctx.bumps.swap_order;
+    swap_order.maker_nonce = UserState::current_nonce(&ctx.accounts.maker_user_state)?;
+    swap_order.lamports = args.lamports;
+    swap_order.created_at = Clock::get()?.unix_timestamp;
+    swap_order.offered = offered;
+    swap_order.wanted = args.wanted;
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{TraitBid, UserState},
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateTraitBidArgs {
+    bid_id: Pubkey,
+    // Verified collection the asset has to belong to.
+    collection: Pubkey,
+    // Keccak merkle root over keccak(asset_id) of every eligible asset, published off-chain.
+    trait_root: [u8; 32],
+    // Paid from the buyer escrow for each asset.
+    price: u64,
+    quantity: u32,
+    // Unix timestamp after which the bid can't be filled, 0 for no expiry.
+    expiry: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(args:CreateTraitBidArgs)]
+pub struct CreateTraitBid<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+
+    #[account(
+      init,
+      payer=buyer,
+      seeds=[
+          PREFIX.as_bytes(),
+          TRAIT_BID.as_bytes(),
+          buyer.key().as_ref(),
+          args.bid_id.as_ref(),
+      ],
+      space=TraitBid::LEN,
+      bump)]
+    trait_bid: Box<Account<'info, TraitBid>>,
+
+    /// CHECK: buyer's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), buyer.key().as_ref()], bump)]
+    buyer_user_state: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+// Nothing is locked here, fills draw from the buyer escrow and fail once it runs short.
+pub fn handle(ctx: Context<CreateTraitBid>, args: CreateTraitBidArgs) -> Result<()> {
+    if args.price > MAX_PRICE || args.price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    let now = Clock::get()?.unix_timestamp;
+    if args.quantity == 0 || (args.expiry != 0 && args.expiry <= now) {
+        return Err(ErrorCode::InvalidTraitBid.into());
+    }
+
+    let trait_bid = &mut ctx.accounts.trait_bid;
+    trait_bid.buyer = ctx.accounts.buyer.key();
+    trait_bid.bid_id = args.bid_id;
+    trait_bid.bump = ctx.bumps.trait_bid;
+    trait_bid.buyer_nonce = UserState::current_nonce(&ctx.accounts.buyer_user_state)?;
+    trait_bid.collection = args.collection;
+    trait_bid.trait_root = args.trait_root;
+    trait_bid.price = args.price;
+    trait_bid.quantity = args.quantity;
+    trait_bid.filled = 0;
+    trait_bid.expiry = args.expiry;
+    trait_bid.created_at = now;
+
+    Ok(())
+}
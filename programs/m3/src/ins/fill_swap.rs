@@ -0,0 +1,328 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::utils::get_asset_id;
+
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, Config, SwapOrder, SwapWantKind, TraderStats, UserState},
+    utils::{
+        assert_verified_collection, calculate_item_fees, decode_metadata_args,
+        load_collection_override, pay_creator_fees, pay_platform_fees, take_accounts,
+        transfer_compressed_nft, verified_collection, verify_creators,
+    },
+};
+use anchor_lang::AnchorDeserialize;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FillSwapOfferedArgs {
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // The Keccak256 hash of the NFTs existing metadata (without the verified flag for the creator changed).
+    // The metadata is retrieved from off-chain data store.
+    data_hash: [u8; 32],
+    // The Keccak256 hash of the NFTs existing creators array (without the verified flag for the creator changed).
+    // The creators array is retrieved from off-chain data store.
+    creator_hash: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // Number of proof accounts for this asset in remaining_accounts.
+    proof_len: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FillSwapWantedArgs {
+    // Borsh serialized Bubblegum MetadataArgs of the asset, used to verify its collection
+    // and creators.
+    metadata_args: Vec<u8>,
+
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+    // Number of proof accounts for this asset in remaining_accounts.
+    proof_len: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FillSwapArgs {
+    // Charged on the escrowed lamports, the maker side pays no fee.
+    taker_fee_bp: u16,
+    // In the same order as the swap_order offered items.
+    offered: Vec<FillSwapOfferedArgs>,
+    // In the same order as the swap_order wanted items.
+    wanted: Vec<FillSwapWantedArgs>,
+}
+
+#[derive(Accounts)]
+pub struct FillSwap<'info> {
+    #[account(mut)]
+    taker: Signer<'info>,
+    /// CHECK: maker checked in swap_order.
+    #[account(mut)]
+    maker: UncheckedAccount<'info>,
+    /// CHECK: meNotary constant
+    #[account(address = ME_NOTARY)]
+    notary: UncheckedAccount<'info>,
+    /// CHECK: that this matches hard-coded auction_house_treasury
+    #[account(mut, address = ME_TREASURY)]
+    platform_treasury: UncheckedAccount<'info>,
+    /// CHECK: program config, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()], bump)]
+    config: UncheckedAccount<'info>,
+
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    #[account(
+      mut,
+      close=maker,
+      constraint= swap_order.maker == maker.key(),
+      seeds=[
+          PREFIX.as_bytes(),
+          SWAP.as_bytes(),
+          maker.key().as_ref(),
+          swap_order.swap_id.as_ref(),
+      ],
+      bump=swap_order.bump)]
+    swap_order: Box<Account<'info, SwapOrder>>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+
+    /// CHECK: maker's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), maker.key().as_ref()], bump)]
+    maker_user_state: UncheckedAccount<'info>,
+
+    #[account(
+      init_if_needed,
+      payer=taker,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), taker.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    taker_stats: Box<Account<'info, TraderStats>>,
+
+    #[account(
+      init_if_needed,
+      payer=taker,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), maker.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    maker_stats: Box<Account<'info, TraderStats>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillSwap<'info>>,
+    args: FillSwapArgs,
+) -> Result<()> {
+    let taker = &ctx.accounts.taker.to_account_info();
+    let maker = &ctx.accounts.maker.to_account_info();
+    let notary = &ctx.accounts.notary;
+    let platform_treasury = &ctx.accounts.platform_treasury;
+    let swap_order_ai = &ctx.accounts.swap_order.to_account_info();
+    let swap_order = &ctx.accounts.swap_order;
+    let system_program = &ctx.accounts.system_program;
+
+    if swap_order.maker_nonce < UserState::current_nonce(&ctx.accounts.maker_user_state)? {
+        return Err(ErrorCode::StaleNonce.into());
+    }
+    if args.offered.len() != swap_order.offered.len()
+        || args.wanted.len() != swap_order.wanted.len()
+    {
+        return Err(ErrorCode::InvalidSwapItems.into());
+    }
+
+    let bump = ctx.bumps.program_as_signer;
+    let seeds = &[PREFIX.as_bytes(), SIGNER.as_bytes(), &[bump][..]];
+
+    // Remaining accounts are the fee recipient accounts, then for each offered item:
+    // tree_authority, merkle_tree and the Merkle proof path. Then for each wanted item:
+    // tree_authority, merkle_tree, leaf_delegate, the creator hash and collection overrides,
+    // (optional) creator addresses, a (RoyaltyRedirect, payee) pair per creator and the Merkle
+    // proof path.
+    let config = Config::load(&ctx.accounts.config)?;
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let fee_recipient_accounts =
+        take_accounts(&mut remaining_accounts, config.fee_recipients.len())?;
+
+    // 1. Transfer the escrowed NFTs to the taker
+    for (item, item_args) in swap_order.offered.iter().zip(args.offered.iter()) {
+        let tree_accounts = take_accounts(&mut remaining_accounts, 2)?;
+        let proof_path = take_accounts(&mut remaining_accounts, item_args.proof_len as usize)?;
+        let (tree_authority, merkle_tree) = (&tree_accounts[0], &tree_accounts[1]);
+
+        if merkle_tree.key() != item.merkle_tree
+            || get_asset_id(merkle_tree.key, item_args.nonce) != item.asset_id
+        {
+            return Err(ErrorCode::AssetIDMismatch.into());
+        }
+
+        transfer_compressed_nft(
+            tree_authority,
+            &ctx.accounts.program_as_signer.to_account_info(),
+            &ctx.accounts.program_as_signer.to_account_info(), // delegate
+            taker,
+            merkle_tree,
+            &ctx.accounts.log_wrapper,
+            &ctx.accounts.compression_program,
+            &ctx.accounts.system_program,
+            proof_path,
+            ctx.accounts.bubblegum_program.key(),
+            item_args.root,
+            item_args.data_hash,
+            item_args.creator_hash,
+            item_args.nonce,
+            item.index,
+            Some(seeds),
+        )?;
+    }
+
+    // 2. Escrowed lamports go to the taker, who pays royalties on them for the wanted NFTs.
+    let lamports = swap_order.lamports;
+    if lamports > 0 {
+        **swap_order_ai.try_borrow_mut_lamports()? -= lamports;
+        **taker.try_borrow_mut_lamports()? += lamports;
+    }
+    let wanted_count = swap_order.wanted.len() as u64;
+    let item_price = lamports / wanted_count;
+    let first_item_price = item_price + lamports % wanted_count;
+
+    // 3. Transfer the wanted NFTs to the maker
+    let mut item_fees = Vec::with_capacity(swap_order.wanted.len());
+    for (i, (want, item_args)) in swap_order.wanted.iter().zip(args.wanted.iter()).enumerate() {
+        let (metadata, data_hash, creator_hash) = decode_metadata_args(&item_args.metadata_args)?;
+        let item_accounts = take_accounts(&mut remaining_accounts, 5)?;
+        let creator_accounts = take_accounts(&mut remaining_accounts, metadata.creators.len())?;
+        let royalty_redirects =
+            take_accounts(&mut remaining_accounts, 2 * metadata.creators.len())?;
+        let proof_path = take_accounts(&mut remaining_accounts, item_args.proof_len as usize)?;
+        let (tree_authority, merkle_tree, leaf_delegate) =
+            (&item_accounts[0], &item_accounts[1], &item_accounts[2]);
+        let (creator_hash_override, collection_override) = (&item_accounts[3], &item_accounts[4]);
+
+        match want.kind {
+            SwapWantKind::AssetId => {
+                if get_asset_id(merkle_tree.key, item_args.nonce) != want.key {
+                    return Err(ErrorCode::AssetIDMismatch.into());
+                }
+            }
+            SwapWantKind::Collection => assert_verified_collection(&metadata, &want.key)?,
+        }
+
+        let collection_override = load_collection_override(
+            creator_hash_override,
+            creator_hash,
+            collection_override,
+            verified_collection(&metadata),
+        )?;
+        let item_price = if i == 0 { first_item_price } else { item_price };
+        if lamports > 0 {
+            let creator_shares: Vec<u16> =
+                metadata.creators.iter().map(|c| c.share as u16).collect();
+            verify_creators(
+                creator_accounts.iter(),
+                creator_shares.clone(),
+                metadata.creators.iter().map(|c| c.verified).collect(),
+                creator_hash,
+            )?;
+            pay_creator_fees(
+                &mut creator_accounts.iter(),
+                creator_shares,
+                royalty_redirects,
+                taker,
+                system_program,
+                item_price,
+                collection_override.as_ref(),
+                metadata.seller_fee_basis_points,
+            )?;
+        }
+        item_fees.push((item_price, collection_override));
+
+        transfer_compressed_nft(
+            tree_authority,
+            taker,
+            leaf_delegate,
+            maker,
+            merkle_tree,
+            &ctx.accounts.log_wrapper,
+            &ctx.accounts.compression_program,
+            &ctx.accounts.system_program,
+            proof_path,
+            ctx.accounts.bubblegum_program.key(),
+            item_args.root,
+            data_hash,
+            creator_hash,
+            item_args.nonce,
+            item_args.index,
+            None, // signer passed through from ctx
+        )?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.taker_stats.roll(now);
+    ctx.accounts.maker_stats.roll(now);
+    let tier_fee_bp = config.tier_fee_bp(
+        ctx.accounts.maker_stats.rolling_volume(),
+        ctx.accounts.taker_stats.rolling_volume(),
+    );
+
+    // 4. Taker pays Treasury on the lamport portion, split between the configured fee recipients.
+    // The fee is charged per wanted asset on its share of the lamports, with the asset's override.
+    let fees = calculate_item_fees(
+        notary,
+        &item_fees,
+        0,
+        args.taker_fee_bp,
+        taker,
+        maker,
+        tier_fee_bp,
+        config.fee_cap(&spl_token::native_mint::ID),
+    )?;
+    pay_platform_fees(
+        taker,
+        &platform_treasury.to_account_info(),
+        fee_recipient_accounts,
+        &config.fee_recipients,
+        system_program,
+        fees.total_platform_fee,
+    )?;
+
+    // Trading against yourself doesn't count towards the fee tiers.
+    let volume = if taker.key() != maker.key() {
+        lamports
+    } else {
+        0
+    };
+    let taker_bump = ctx.bumps.taker_stats;
+    ctx.accounts
+        .taker_stats
+        .record_fill(taker.key(), taker_bump, now, volume);
+    let maker_bump = ctx.bumps.maker_stats;
+    ctx.accounts
+        .maker_stats
+        .record_fill(maker.key(), maker_bump, now, volume);
+
+    msg!(
+        "{{\"lamports\":{},\"taker_fee\":{},\"total_platform_fee\":{}}}",
+        lamports,
+        fees.taker_fee,
+        fees.total_platform_fee
+    );
+
+    Ok(())
+}
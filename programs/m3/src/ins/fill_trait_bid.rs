@@ -0,0 +1,301 @@
+use anchor_lang::{prelude::*, AccountsClose};
+use solana_program::keccak;
+
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{
+        BubblegumProgram, BuyerEscrow, Config, TraderStats, TraitBid, TreeConfigAnchor, UserState,
+    },
+    utils::{
+        assert_verified_collection, calculate_fees, decode_metadata_args, load_collection_override,
+        pay_creator_fees, pay_platform_fees, take_accounts, transfer_compressed_nft,
+        verified_collection, verify_creators, verify_merkle_proof,
+    },
+};
+use anchor_lang::AnchorDeserialize;
+use mpl_bubblegum::utils::get_asset_id;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FillTraitBidArgs {
+    // Bids are the maker side, only the taker fee applies.
+    taker_fee_bp: u16,
+    // Borsh serialized Bubblegum MetadataArgs of the asset, used to verify its collection
+    // and creators.
+    metadata_args: Vec<u8>,
+    // Proof of keccak(asset_id) against the bid's trait_root.
+    trait_proof: Vec<[u8; 32]>,
+
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args:FillTraitBidArgs)]
+pub struct FillTraitBid<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    /// CHECK: buyer checked in trait_bid, receives the asset.
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+    /// CHECK: meNotary constant
+    #[account(address = ME_NOTARY)]
+    notary: UncheckedAccount<'info>,
+    /// CHECK: that this matches hard-coded auction_house_treasury
+    #[account(mut, address = ME_TREASURY)]
+    platform_treasury: UncheckedAccount<'info>,
+    /// CHECK: program config, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()], bump)]
+    config: UncheckedAccount<'info>,
+    /// CHECK: override keyed by the asset's creator hash, may not be initialized. Checked in
+    /// load_collection_override.
+    creator_hash_override: UncheckedAccount<'info>,
+    /// CHECK: override keyed by the asset's verified collection, may not be initialized. Checked
+    /// in load_collection_override.
+    collection_override: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      has_one=buyer,
+      seeds=[
+          PREFIX.as_bytes(),
+          TRAIT_BID.as_bytes(),
+          buyer.key().as_ref(),
+          trait_bid.bid_id.as_ref(),
+      ],
+      bump=trait_bid.bump)]
+    trait_bid: Box<Account<'info, TraitBid>>,
+
+    #[account(
+      mut,
+      has_one=buyer,
+      seeds=[PREFIX.as_bytes(), BUYER_ESCROW.as_bytes(), buyer.key().as_ref()],
+      bump=buyer_escrow.bump)]
+    buyer_escrow: Box<Account<'info, BuyerEscrow>>,
+
+    /// CHECK: buyer's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), buyer.key().as_ref()], bump)]
+    buyer_user_state: UncheckedAccount<'info>,
+
+    // ==== cNFT transfer args ==== //
+    #[account(
+      mut,
+      seeds = [merkle_tree.key().as_ref()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+    )]
+    /// CHECK: This account is neither written to nor read from.
+    pub tree_authority: Account<'info, TreeConfigAnchor>,
+    // The NFT delegate. Transfers must be signed by either the NFT owner or NFT delegate.
+    /// CHECK: This account is checked in the Bubblegum transfer instruction
+    leaf_delegate: UncheckedAccount<'info>,
+    // The account that contains the Merkle tree, initialized by create_tree.
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    #[account(
+      init_if_needed,
+      payer=seller,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), seller.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    seller_stats: Box<Account<'info, TraderStats>>,
+
+    #[account(
+      init_if_needed,
+      payer=seller,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), buyer.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    buyer_stats: Box<Account<'info, TraderStats>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillTraitBid<'info>>,
+    args: FillTraitBidArgs,
+) -> Result<()> {
+    let seller = &ctx.accounts.seller.to_account_info();
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let buyer_escrow = &ctx.accounts.buyer_escrow.to_account_info();
+    let notary = &ctx.accounts.notary;
+    let platform_treasury = &ctx.accounts.platform_treasury;
+    let system_program = &ctx.accounts.system_program;
+    let trait_bid = &mut ctx.accounts.trait_bid;
+
+    // Bids made before the buyer bumped their nonce are cancelled.
+    if trait_bid.buyer_nonce < UserState::current_nonce(&ctx.accounts.buyer_user_state)? {
+        return Err(ErrorCode::StaleNonce.into());
+    }
+    let now = Clock::get()?.unix_timestamp;
+    if (trait_bid.expiry != 0 && now > trait_bid.expiry) || trait_bid.filled >= trait_bid.quantity {
+        return Err(ErrorCode::InvalidTraitBid.into());
+    }
+
+    let (metadata, data_hash, creator_hash) = decode_metadata_args(&args.metadata_args)?;
+    assert_verified_collection(&metadata, &trait_bid.collection)?;
+    let asset_id = get_asset_id(&ctx.accounts.merkle_tree.key(), args.nonce);
+    let leaf = keccak::hash(asset_id.as_ref()).to_bytes();
+    if !verify_merkle_proof(&args.trait_proof, trait_bid.trait_root, leaf) {
+        return Err(ErrorCode::AssetNotEligible.into());
+    }
+
+    let price = trait_bid.price;
+    let available = buyer_escrow
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(BuyerEscrow::LEN));
+    if price > available {
+        msg!(
+            "Buyer escrow short: {{\"needed\":{},\"available\":{}}}",
+            price,
+            available
+        );
+        return Err(ErrorCode::InsufficientEscrowBalance.into());
+    }
+
+    // The asset's override replaces the default royalty share and fees.
+    let collection_override = load_collection_override(
+        &ctx.accounts.creator_hash_override,
+        creator_hash,
+        &ctx.accounts.collection_override,
+        verified_collection(&metadata),
+    )?;
+
+    // Remaining accounts are 1. (Optional) creator addresses, 2. a (RoyaltyRedirect, payee) pair
+    // per creator, 3. fee recipient accounts and 4. Merkle proof path.
+    let creator_shares: Vec<u16> = metadata.creators.iter().map(|c| c.share as u16).collect();
+    let creator_verified: Vec<bool> = metadata.creators.iter().map(|c| c.verified).collect();
+    let config = Config::load(&ctx.accounts.config)?;
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let creator_accounts = take_accounts(&mut remaining_accounts, creator_shares.len())?;
+    let royalty_redirects = take_accounts(&mut remaining_accounts, 2 * creator_shares.len())?;
+    let fee_recipient_accounts =
+        take_accounts(&mut remaining_accounts, config.fee_recipients.len())?;
+    let proof_path = remaining_accounts;
+
+    // 1. Transfer NFT to the Buyer
+    transfer_compressed_nft(
+        &ctx.accounts.tree_authority.to_account_info(),
+        seller,
+        &ctx.accounts.leaf_delegate.to_account_info(), // delegate
+        buyer,
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        &ctx.accounts.system_program,
+        proof_path,
+        ctx.accounts.bubblegum_program.key(),
+        args.root,
+        data_hash,
+        creator_hash,
+        args.nonce,
+        args.index,
+        None, // signer passed through from ctx
+    )?;
+
+    // 2. Buyer escrow pays Seller
+    **buyer_escrow.try_borrow_mut_lamports()? -= price;
+    **seller.try_borrow_mut_lamports()? += price;
+
+    // 3. Seller pays Creator Fees out of the proceeds
+    verify_creators(
+        creator_accounts.iter(),
+        creator_shares.clone(),
+        creator_verified,
+        creator_hash,
+    )?;
+    pay_creator_fees(
+        &mut creator_accounts.iter(),
+        creator_shares,
+        royalty_redirects,
+        seller,
+        system_program,
+        price,
+        collection_override.as_ref(),
+        metadata.seller_fee_basis_points,
+    )?;
+
+    ctx.accounts.seller_stats.roll(now);
+    ctx.accounts.buyer_stats.roll(now);
+    let tier_fee_bp = config.tier_fee_bp(
+        ctx.accounts.buyer_stats.rolling_volume(),
+        ctx.accounts.seller_stats.rolling_volume(),
+    );
+
+    // 4. Seller pays Treasury, split between the configured fee recipients.
+    let fees = calculate_fees(
+        notary,
+        price,
+        0,
+        args.taker_fee_bp,
+        seller,
+        buyer,
+        (0, tier_fee_bp.1),
+        collection_override.as_ref(),
+        config.fee_cap(&spl_token::native_mint::ID),
+    )?;
+    pay_platform_fees(
+        seller,
+        &platform_treasury.to_account_info(),
+        fee_recipient_accounts,
+        &config.fee_recipients,
+        system_program,
+        fees.total_platform_fee,
+    )?;
+
+    trait_bid.filled = trait_bid
+        .filled
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    // Trading against yourself doesn't count towards the fee tiers.
+    let volume = if seller.key() != buyer.key() {
+        price
+    } else {
+        0
+    };
+    let seller_bump = ctx.bumps.seller_stats;
+    ctx.accounts
+        .seller_stats
+        .record_fill(seller.key(), seller_bump, now, volume);
+    let buyer_bump = ctx.bumps.buyer_stats;
+    ctx.accounts
+        .buyer_stats
+        .record_fill(buyer.key(), buyer_bump, now, volume);
+
+    msg!(
+        "{{\"asset_id\":\"{}\",\"buyer\":\"{}\",\"seller\":\"{}\",\"price\":{},\"taker_fee\":{},\"total_platform_fee\":{},\"filled\":{},\"quantity\":{}}}",
+        asset_id,
+        trait_bid.buyer,
+        seller.key(),
+        price,
+        fees.taker_fee,
+        fees.total_platform_fee,
+        trait_bid.filled,
+        trait_bid.quantity
+    );
+
+    // The rent goes back to the buyer once the bid is fully filled.
+    if trait_bid.filled == trait_bid.quantity {
+        trait_bid.close(buyer.clone())?;
+    }
+
+    Ok(())
+}
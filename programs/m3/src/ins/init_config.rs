@@ -0,0 +1,27 @@
+use crate::{constants::*, states::Config};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitConfig<'info> {
+    #[account(mut, address = CONFIG_AUTHORITY)]
+    authority: Signer<'info>,
+
+    #[account(
+      init,
+      payer=authority,
+      seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()],
+      space=Config::LEN,
+      bump)]
+    config: Box<Account<'info, Config>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<InitConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.authority = ctx.accounts.authority.key();
+    config.bump = ctx.bumps.config;
+    config.seller_cancel_cooldown_seconds = DEFAULT_SELLER_CANCEL_COOLDOWN_SECONDS;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::{constants::*, errors::ErrorCode, states::SellerTradeState};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LayawayDefaultArgs {
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args:LayawayDefaultArgs)]
+pub struct LayawayDefault<'info> {
+    /// CHECK: only used to derive the seller_trade_state.
+    merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      seeds=[
+          PREFIX.as_bytes(),
+          merkle_tree.key().as_ref(),
+          args.index.to_le_bytes().as_ref(),
+      ],
+      bump=seller_trade_state.bump)]
+    seller_trade_state: Box<Account<'info, SellerTradeState>>,
+
+    /// CHECK: receives the seller's part of the deposit, checked against seller_trade_state.
+    #[account(mut, address = seller_trade_state.payout_address())]
+    payout_address: UncheckedAccount<'info>,
+
+    /// CHECK: gets the rest of the deposit back, checked against seller_trade_state.
+    #[account(mut, address = seller_trade_state.layaway_buyer)]
+    layaway_buyer: UncheckedAccount<'info>,
+}
+
+// Permissionless crank once a layaway deadline passed: splits the deposit between the seller and
+// the buyer by the seller's penalty and unlocks the listing.
+pub fn handle(ctx: Context<LayawayDefault>, _args: LayawayDefaultArgs) -> Result<()> {
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+    if !seller_trade_state.is_locked()
+        || Clock::get()?.unix_timestamp <= seller_trade_state.layaway_deadline
+    {
+        return Err(ErrorCode::InvalidLayaway.into());
+    }
+
+    let deposit = seller_trade_state.layaway_deposit;
+    let seller_amount = (deposit as u128)
+        .checked_mul(seller_trade_state.layaway_penalty_bp as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::NumericalOverflow)? as u64;
+    let buyer_amount = deposit - seller_amount;
+
+    let seller_trade_state_ai = seller_trade_state.to_account_info();
+    **seller_trade_state_ai.try_borrow_mut_lamports()? -= deposit;
+    **ctx.accounts.payout_address.try_borrow_mut_lamports()? += seller_amount;
+    **ctx.accounts.layaway_buyer.try_borrow_mut_lamports()? += buyer_amount;
+
+    msg!(
+        "{{\"asset_id\":\"{}\",\"layaway_buyer\":\"{}\",\"seller_amount\":{},\"buyer_amount\":{}}}",
+        seller_trade_state.asset_id,
+        seller_trade_state.layaway_buyer,
+        seller_amount,
+        buyer_amount
+    );
+    seller_trade_state.clear_layaway();
+
+    Ok(())
+}
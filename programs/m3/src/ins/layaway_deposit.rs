@@ -0,0 +1,107 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{SellerTradeState, UserState},
+};
+use anchor_lang::prelude::*;
+use solana_program::{program::invoke, system_instruction};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LayawayDepositArgs {
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+    // Held by the listing until the buyer completes the purchase with buy_now or defaults.
+    deposit: u64,
+    // Most the buyer is willing to pay, the deposit fails if the listing price is higher.
+    max_price: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args:LayawayDepositArgs)]
+pub struct LayawayDeposit<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+
+    /// CHECK: only used to derive the seller_trade_state.
+    merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      seeds=[
+          PREFIX.as_bytes(),
+          merkle_tree.key().as_ref(),
+          args.index.to_le_bytes().as_ref(),
+      ],
+      bump=seller_trade_state.bump)]
+    seller_trade_state: Box<Account<'info, SellerTradeState>>,
+
+    /// CHECK: seller's UserState, may not be initialized yet.
+    #[account(
+      seeds=[PREFIX.as_bytes(), USER.as_bytes(), seller_trade_state.seller.as_ref()],
+      bump)]
+    seller_user_state: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+// Locks the listing for the buyer, who has LAYAWAY_DURATION_SECONDS to pay the remainder through
+// buy_now. Anyone can settle the deposit with layaway_default once the deadline passed.
+pub fn handle(ctx: Context<LayawayDeposit>, args: LayawayDepositArgs) -> Result<()> {
+    let buyer = &ctx.accounts.buyer;
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+
+    if !seller_trade_state.layaway_enabled {
+        return Err(ErrorCode::InvalidLayaway.into());
+    }
+    if seller_trade_state.is_locked() {
+        return Err(ErrorCode::ListingLocked.into());
+    }
+    if seller_trade_state.seller_nonce < UserState::current_nonce(&ctx.accounts.seller_user_state)?
+    {
+        return Err(ErrorCode::StaleNonce.into());
+    }
+    if seller_trade_state.cancel_requested_at != 0 {
+        return Err(ErrorCode::CancelPending.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    seller_trade_state.apply_pending_price(now);
+    // The terms are fixed while locked, a scheduled increase would otherwise land on the buyer.
+    if seller_trade_state.pending_price_effective_at != 0 {
+        return Err(ErrorCode::InvalidLayaway.into());
+    }
+    let buyer_price = seller_trade_state.buyer_price;
+    if buyer_price > args.max_price {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+    let min_deposit = buyer_price
+        .checked_mul(LAYAWAY_MIN_DEPOSIT_BP)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        / 10000;
+    if args.deposit == 0 || args.deposit < min_deposit || args.deposit >= buyer_price {
+        return Err(ErrorCode::InvalidLayaway.into());
+    }
+
+    invoke(
+        &system_instruction::transfer(buyer.key, &seller_trade_state.key(), args.deposit),
+        &[
+            buyer.to_account_info(),
+            seller_trade_state.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    seller_trade_state.layaway_buyer = buyer.key();
+    seller_trade_state.layaway_deposit = args.deposit;
+    seller_trade_state.layaway_deadline = now + LAYAWAY_DURATION_SECONDS;
+
+    msg!(
+        "{{\"asset_id\":\"{}\",\"layaway_buyer\":\"{}\",\"deposit\":{},\"deadline\":{}}}",
+        seller_trade_state.asset_id,
+        buyer.key(),
+        args.deposit,
+        seller_trade_state.layaway_deadline
+    );
+
+    Ok(())
+}
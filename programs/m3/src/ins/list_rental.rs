@@ -0,0 +1,131 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, RentalListing, TreeConfigAnchor},
+    utils::{decode_metadata_args, transfer_compressed_nft, verified_collection},
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use mpl_bubblegum::utils::get_asset_id;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ListRentalArgs {
+    // Rent for one period in lamports.
+    price_per_period: u64,
+    period_seconds: i64,
+    // Most periods a renter can pay for at once.
+    max_periods: u32,
+    // Borsh serialized Bubblegum MetadataArgs of the asset, its collection is kept for the
+    // collection override of rent payments.
+    metadata_args: Vec<u8>,
+
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args:ListRentalArgs)]
+pub struct ListRental<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    // ==== cNFT transfer args ==== //
+    #[account(
+      mut,
+      seeds = [merkle_tree.key().as_ref()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+    )]
+    /// CHECK: This account is neither written to nor read from.
+    pub tree_authority: Account<'info, TreeConfigAnchor>,
+    // The NFT delegate. Transfers must be signed by either the NFT owner or NFT delegate.
+    /// CHECK: This account is checked in the Bubblegum transfer instruction
+    leaf_delegate: UncheckedAccount<'info>,
+    // The account that contains the Merkle tree, initialized by create_tree.
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    #[account(
+      init,
+      payer=owner,
+      seeds=[
+          PREFIX.as_bytes(),
+          RENTAL.as_bytes(),
+          merkle_tree.key().as_ref(),
+          args.index.to_le_bytes().as_ref(),
+      ],
+      space=RentalListing::LEN,
+      bump)]
+    rental_listing: Box<Account<'info, RentalListing>>, // escrow to hold the ownership of the cnft
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, ListRental<'info>>,
+    args: ListRentalArgs,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+
+    if args.price_per_period > MAX_PRICE || args.price_per_period == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.period_seconds <= 0 || args.max_periods == 0 {
+        return Err(ErrorCode::InvalidRental.into());
+    }
+
+    // Bubblegum verifies the hashes, so they can be trusted for royalties and the return later.
+    // The asset is held by its own rental_listing, so its owner on-chain points at the renter.
+    let (metadata, data_hash, creator_hash) = decode_metadata_args(&args.metadata_args)?;
+    transfer_compressed_nft(
+        &ctx.accounts.tree_authority.to_account_info(),
+        &owner.to_account_info(),
+        &ctx.accounts.leaf_delegate.to_account_info(), // delegate
+        &ctx.accounts.rental_listing.to_account_info(),
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        ctx.accounts.bubblegum_program.key(),
+        args.root,
+        data_hash,
+        creator_hash,
+        args.nonce,
+        args.index,
+        None, // signer passed through from ctx
+    )?;
+
+    let rental_listing = &mut ctx.accounts.rental_listing;
+    rental_listing.owner = owner.key();
+    rental_listing.asset_id = get_asset_id(&ctx.accounts.merkle_tree.key(), args.nonce);
+    rental_listing.merkle_tree = ctx.accounts.merkle_tree.key();
+    rental_listing.index = args.index;
+    rental_listing.nonce = args.nonce;
+    rental_listing.data_hash = data_hash;
+    rental_listing.creator_hash = creator_hash;
+    rental_listing.seller_fee_basis_points = metadata.seller_fee_basis_points;
+    rental_listing.collection = verified_collection(&metadata).copied().unwrap_or_default();
+    rental_listing.bump = ctx.bumps.rental_listing;
+    rental_listing.price_per_period = args.price_per_period;
+    rental_listing.period_seconds = args.period_seconds;
+    rental_listing.max_periods = args.max_periods;
+    rental_listing.created_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,286 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::{
+    instructions::{
+        MintToCollectionV1Cpi, MintToCollectionV1CpiAccounts, MintToCollectionV1InstructionArgs,
+    },
+    types::{Collection, MetadataArgs},
+    utils::get_asset_id,
+};
+use solana_program::{keccak, program::invoke, system_instruction};
+
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, Config, Launchpad, LaunchpadMintCount, TreeConfigAnchor},
+    utils::{calculate_fees, pay_platform_fees, verify_merkle_proof},
+};
+use anchor_lang::AnchorDeserialize;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MintBuyArgs {
+    // Execute Sale Args
+    maker_fee_bp: i16,
+    taker_fee_bp: u16,
+
+    // Index into launchpad.phases, required when the launchpad has phases.
+    phase: Option<u8>,
+    // Proof of keccak(buyer) against the phase's allowlist_root.
+    allowlist_proof: Vec<[u8; 32]>,
+}
+
+#[derive(Accounts)]
+#[instruction(args:MintBuyArgs)]
+pub struct MintBuy<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+    /// CHECK: creator checked in launchpad.
+    #[account(mut)]
+    creator: UncheckedAccount<'info>,
+    /// CHECK: meNotary constant
+    #[account(address = ME_NOTARY)]
+    notary: UncheckedAccount<'info>,
+    /// CHECK: that this matches hard-coded auction_house_treasury
+    #[account(mut, address = ME_TREASURY)]
+    platform_treasury: UncheckedAccount<'info>,
+    /// CHECK: program config, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()], bump)]
+    config: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      has_one=creator,
+      has_one=merkle_tree,
+      has_one=collection_mint,
+      seeds=[
+          PREFIX.as_bytes(),
+          LAUNCHPAD.as_bytes(),
+          launchpad.creator.as_ref(),
+          launchpad.launchpad_id.as_ref(),
+      ],
+      bump=launchpad.bump)]
+    launchpad: Box<Account<'info, Launchpad>>,
+
+    // Only for mints through a phase, public sales without phases don't track wallets.
+    #[account(
+      init_if_needed,
+      payer=buyer,
+      seeds=[
+          PREFIX.as_bytes(),
+          LAUNCHPAD_MINT_COUNT.as_bytes(),
+          launchpad.key().as_ref(),
+          buyer.key().as_ref(),
+      ],
+      space=LaunchpadMintCount::LEN,
+      bump)]
+    mint_count: Option<Box<Account<'info, LaunchpadMintCount>>>,
+
+    /// CHECK: program_as_signer, tree delegate and collection authority of the launchpad.
+    #[account(
+      seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()],
+      bump)]
+    program_as_signer: UncheckedAccount<'info>,
+
+    // ==== cNFT mint args ==== //
+    #[account(
+      mut,
+      seeds = [merkle_tree.key().as_ref()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+    )]
+    tree_authority: Account<'info, TreeConfigAnchor>,
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: checked in launchpad, verified by Token Metadata.
+    collection_mint: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the Bubblegum mint instruction
+    #[account(mut)]
+    collection_metadata: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the Bubblegum mint instruction
+    collection_edition: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer's collection authority record, checked by Token Metadata.
+    collection_authority_record: UncheckedAccount<'info>,
+    /// CHECK: Bubblegum's collection cpi signer
+    #[account(
+      seeds = [BUBBLEGUM_COLLECTION_CPI.as_bytes()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+    )]
+    bubblegum_signer: UncheckedAccount<'info>,
+    /// CHECK: Token Metadata program
+    #[account(address = TOKEN_METADATA_PROGRAM)]
+    token_metadata_program: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+}
+
+// Remaining accounts are the config's fee recipients in order.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintBuy<'info>>,
+    args: MintBuyArgs,
+) -> Result<()> {
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let creator = &ctx.accounts.creator.to_account_info();
+    let notary = &ctx.accounts.notary;
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let launchpad = &mut ctx.accounts.launchpad;
+
+    let now = Clock::get()?.unix_timestamp;
+    if launchpad.minted >= launchpad.supply {
+        return Err(ErrorCode::SoldOut.into());
+    }
+    let number = launchpad
+        .minted
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    let price = match (launchpad.phases.is_empty(), args.phase) {
+        (true, None) => {
+            if now < launchpad.start_at {
+                return Err(ErrorCode::SaleNotStarted.into());
+            }
+            launchpad.price
+        }
+        (false, Some(index)) => {
+            let phase = launchpad
+                .phases
+                .get(index as usize)
+                .ok_or(ErrorCode::InvalidLaunchpad)?;
+            if now < phase.start_at || now >= phase.end_at {
+                return Err(ErrorCode::PhaseNotActive.into());
+            }
+            if let Some(allowlist_root) = phase.allowlist_root {
+                let leaf = keccak::hash(buyer.key.as_ref()).to_bytes();
+                if !verify_merkle_proof(&args.allowlist_proof, allowlist_root, leaf) {
+                    return Err(ErrorCode::NotAllowlisted.into());
+                }
+            }
+            let mint_count = ctx
+                .accounts
+                .mint_count
+                .as_mut()
+                .ok_or(ErrorCode::InvalidLaunchpad)?;
+            mint_count.launchpad = launchpad.key();
+            mint_count.wallet = buyer.key();
+            mint_count.bump = ctx.bumps.mint_count;
+            let minted = &mut mint_count.minted[index as usize];
+            if phase.wallet_cap > 0 && *minted >= phase.wallet_cap {
+                return Err(ErrorCode::WalletCapReached.into());
+            }
+            *minted = minted.checked_add(1).ok_or(ErrorCode::NumericalOverflow)?;
+            phase.price
+        }
+        _ => return Err(ErrorCode::InvalidLaunchpad.into()),
+    };
+
+    // 1. Calculate fees, the creator is the seller of a primary sale.
+    let config = Config::load(&ctx.accounts.config)?;
+    let fees = calculate_fees(
+        notary,
+        price,
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        buyer,
+        creator,
+        (DEFAULT_MAKER_FEE_BP, DEFAULT_TAKER_FEE_BP),
+        None,
+        config.fee_cap(&spl_token::native_mint::ID),
+    )?;
+
+    // 2. Buyer pays Creator
+    if fees.seller_will_get_from_buyer > 0 {
+        invoke(
+            &system_instruction::transfer(buyer.key, creator.key, fees.seller_will_get_from_buyer),
+            &[buyer.clone(), creator.clone(), system_program.clone()],
+        )?;
+    }
+
+    // 3. Buyer pays Treasury, split between the configured fee recipients.
+    let platform_fees_paid = pay_platform_fees(
+        buyer,
+        &ctx.accounts.platform_treasury.to_account_info(),
+        ctx.remaining_accounts,
+        &config.fee_recipients,
+        system_program,
+        fees.total_platform_fee,
+    )?;
+
+    // 4. Mint the numbered asset to the buyer
+    let mut metadata = MetadataArgs::try_from_slice(&launchpad.metadata_template)
+        .map_err(|_| ErrorCode::InvalidMetadataArgs)?;
+    metadata.name = format!("{} #{}", metadata.name, number);
+    metadata.uri = format!("{}{}.json", metadata.uri, number);
+    metadata.primary_sale_happened = true;
+    metadata.collection = Some(Collection {
+        verified: false,
+        key: launchpad.collection_mint,
+    });
+    // None of the creators sign the mint.
+    for creator in metadata.creators.iter_mut() {
+        creator.verified = false;
+    }
+
+    let asset_id = get_asset_id(
+        &ctx.accounts.merkle_tree.key(),
+        ctx.accounts.tree_authority.num_minted,
+    );
+    let program_as_signer = &ctx.accounts.program_as_signer.to_account_info();
+    let bump = ctx.bumps.program_as_signer;
+    let seeds = &[PREFIX.as_bytes(), SIGNER.as_bytes(), &[bump][..]];
+    MintToCollectionV1Cpi::new(
+        &ctx.accounts.bubblegum_program.to_account_info(),
+        MintToCollectionV1CpiAccounts {
+            tree_config: &ctx.accounts.tree_authority.to_account_info(),
+            leaf_owner: buyer,
+            leaf_delegate: buyer,
+            merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+            payer: buyer,
+            tree_creator_or_delegate: program_as_signer,
+            collection_authority: program_as_signer,
+            collection_authority_record_pda: Some(
+                &ctx.accounts.collection_authority_record.to_account_info(),
+            ),
+            collection_mint: &ctx.accounts.collection_mint.to_account_info(),
+            collection_metadata: &ctx.accounts.collection_metadata.to_account_info(),
+            collection_edition: &ctx.accounts.collection_edition.to_account_info(),
+            bubblegum_signer: &ctx.accounts.bubblegum_signer.to_account_info(),
+            log_wrapper: &ctx.accounts.log_wrapper.to_account_info(),
+            compression_program: &ctx.accounts.compression_program.to_account_info(),
+            token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+            system_program,
+        },
+        MintToCollectionV1InstructionArgs { metadata },
+    )
+    .invoke_signed(&[seeds])?;
+
+    launchpad.minted = number;
+
+    let fee_recipients_log = platform_fees_paid
+        .iter()
+        .map(|(address, amount)| format!("{{\"address\":\"{}\",\"amount\":{}}}", address, amount))
+        .collect::<Vec<_>>()
+        .join(",");
+    msg!(
+        "{{\"launchpad\":\"{}\",\"asset_id\":\"{}\",\"buyer\":\"{}\",\"number\":{},\"phase\":{},\"price\":{},\"maker_fee\":{},\"taker_fee\":{},\"total_platform_fee\":{},\"fee_recipients\":[{}]}}",
+        launchpad.key(),
+        asset_id,
+        buyer.key(),
+        number,
+        args.phase.map_or(-1, |phase| phase as i16),
+        price,
+        fees.maker_fee,
+        fees.taker_fee,
+        fees.total_platform_fee,
+        fee_recipients_log
+    );
+
+    Ok(())
+}
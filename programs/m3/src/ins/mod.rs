@@ -4,5 +4,146 @@ pub use sell::*;
 pub mod buy_now;
 pub use buy_now::*;
 
+pub mod close_fee_override_receipt;
+pub use close_fee_override_receipt::*;
+
 pub mod cancel_sell;
 pub use cancel_sell::*;
+
+pub mod buy_signed_order;
+pub use buy_signed_order::*;
+
+pub mod close_signed_order_receipt;
+pub use close_signed_order_receipt::*;
+
+pub mod bump_nonce;
+pub use bump_nonce::*;
+
+pub mod request_cancel_sell;
+pub use request_cancel_sell::*;
+
+pub mod init_config;
+pub use init_config::*;
+
+pub mod update_seller_cancel_cooldown;
+pub use update_seller_cancel_cooldown::*;
+
+pub mod create_pool;
+pub use create_pool::*;
+
+pub mod update_pool;
+pub use update_pool::*;
+
+pub mod pool_deposit_sol;
+pub use pool_deposit_sol::*;
+
+pub mod pool_withdraw_sol;
+pub use pool_withdraw_sol::*;
+
+pub mod close_pool;
+pub use close_pool::*;
+
+pub mod pool_deposit_nft;
+pub use pool_deposit_nft::*;
+
+pub mod pool_withdraw_nft;
+pub use pool_withdraw_nft::*;
+
+pub mod buy_from_pool;
+pub use buy_from_pool::*;
+
+pub mod sell_into_pool;
+pub use sell_into_pool::*;
+
+pub mod sell_bundle;
+pub use sell_bundle::*;
+
+pub mod buy_bundle;
+pub use buy_bundle::*;
+
+pub mod cancel_bundle;
+pub use cancel_bundle::*;
+
+pub mod create_swap;
+pub use create_swap::*;
+
+pub mod fill_swap;
+pub use fill_swap::*;
+
+pub mod cancel_swap;
+pub use cancel_swap::*;
+
+pub mod transfer_listing;
+pub use transfer_listing::*;
+
+pub mod update_fee_tiers;
+pub use update_fee_tiers::*;
+
+pub mod update_fee_recipients;
+pub use update_fee_recipients::*;
+
+pub mod update_fee_caps;
+pub use update_fee_caps::*;
+
+pub mod set_collection_override;
+pub use set_collection_override::*;
+
+pub mod buyer_escrow_deposit;
+pub use buyer_escrow_deposit::*;
+
+pub mod buyer_escrow_withdraw;
+pub use buyer_escrow_withdraw::*;
+
+pub mod buy_now_with_intent;
+pub use buy_now_with_intent::*;
+
+pub mod close_buy_intent_receipt;
+pub use close_buy_intent_receipt::*;
+
+pub mod set_royalty_redirect;
+pub use set_royalty_redirect::*;
+
+pub mod layaway_deposit;
+pub use layaway_deposit::*;
+
+pub mod layaway_default;
+pub use layaway_default::*;
+
+pub mod list_rental;
+pub use list_rental::*;
+
+pub mod rent_asset;
+pub use rent_asset::*;
+
+pub mod close_rental;
+pub use close_rental::*;
+
+pub mod create_loan_offer;
+pub use create_loan_offer::*;
+
+pub mod cancel_loan_offer;
+pub use cancel_loan_offer::*;
+
+pub mod take_loan;
+pub use take_loan::*;
+
+pub mod repay_loan;
+pub use repay_loan::*;
+
+pub mod foreclose_loan;
+pub use foreclose_loan::*;
+
+pub mod create_launchpad;
+pub use create_launchpad::*;
+
+pub mod mint_buy;
+pub use mint_buy::*;
+
+pub mod create_trait_bid;
+pub use create_trait_bid::*;
+
+pub mod cancel_trait_bid;
+pub use cancel_trait_bid::*;
+
+pub mod fill_trait_bid;
+pub use fill_trait_bid::*;
@@ -0,0 +1,135 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, Pool, PoolAsset, TreeConfigAnchor},
+    utils::{assert_verified_collection, decode_metadata_args, transfer_compressed_nft},
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use mpl_bubblegum::utils::get_asset_id;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PoolDepositNftArgs {
+    // Borsh serialized Bubblegum MetadataArgs of the asset, used to verify its collection.
+    metadata_args: Vec<u8>,
+
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args:PoolDepositNftArgs)]
+pub struct PoolDepositNft<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=owner,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL.as_bytes(),
+          owner.key().as_ref(),
+          pool.uuid.as_ref(),
+      ],
+      bump=pool.bump)]
+    pool: Box<Account<'info, Pool>>,
+
+    /// CHECK: program_as_signer
+    #[account(
+      seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()],
+      bump)]
+    program_as_signer: UncheckedAccount<'info>, // escrow to hold the ownership of the cnft
+
+    // ==== cNFT transfer args ==== //
+    #[account(
+      mut,
+      seeds = [merkle_tree.key().as_ref()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+    )]
+    /// CHECK: This account is neither written to nor read from.
+    pub tree_authority: Account<'info, TreeConfigAnchor>,
+    // The NFT delegate. Transfers must be signed by either the NFT owner or NFT delegate.
+    /// CHECK: This account is checked in the Bubblegum transfer instruction
+    leaf_delegate: UncheckedAccount<'info>,
+    // The account that contains the Merkle tree, initialized by create_tree.
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    #[account(
+      init,
+      payer=owner,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL_ASSET.as_bytes(),
+          merkle_tree.key().as_ref(),
+          args.index.to_le_bytes().as_ref(),
+      ],
+      space=PoolAsset::LEN,
+      bump)]
+    pool_asset: Box<Account<'info, PoolAsset>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, PoolDepositNft<'info>>,
+    args: PoolDepositNftArgs,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let pool = &mut ctx.accounts.pool;
+
+    let (metadata, data_hash, creator_hash) = decode_metadata_args(&args.metadata_args)?;
+    assert_verified_collection(&metadata, &pool.collection)?;
+
+    transfer_compressed_nft(
+        &ctx.accounts.tree_authority.to_account_info(),
+        &owner.to_account_info(),
+        &ctx.accounts.leaf_delegate.to_account_info(), // delegate
+        &ctx.accounts.program_as_signer.to_account_info(),
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        ctx.accounts.bubblegum_program.key(),
+        args.root,
+        data_hash,
+        creator_hash,
+        args.nonce,
+        args.index,
+        None, // signer passed through from ctx
+    )?;
+
+    let pool_asset = &mut ctx.accounts.pool_asset;
+    pool_asset.pool = pool.key();
+    pool_asset.asset_id = get_asset_id(&ctx.accounts.merkle_tree.key(), args.nonce);
+    pool_asset.merkle_tree = ctx.accounts.merkle_tree.key();
+    pool_asset.index = args.index;
+    pool_asset.rent_payer = owner.key();
+    pool_asset.bump = ctx.bumps.pool_asset;
+
+    pool.nft_count = pool
+        .nft_count
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    pool.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::{constants::*, states::Pool};
+use anchor_lang::prelude::*;
+use solana_program::{program::invoke, system_instruction};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PoolDepositSolArgs {
+    amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct PoolDepositSol<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=owner,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL.as_bytes(),
+          owner.key().as_ref(),
+          pool.uuid.as_ref(),
+      ],
+      bump=pool.bump)]
+    pool: Box<Account<'info, Pool>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<PoolDepositSol>, args: PoolDepositSolArgs) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let pool = &ctx.accounts.pool;
+
+    invoke(
+        &system_instruction::transfer(owner.key, &pool.key(), args.amount),
+        &[
+            owner.to_account_info(),
+            pool.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    Ok(())
+}
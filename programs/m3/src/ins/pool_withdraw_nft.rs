@@ -0,0 +1,135 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, Pool, PoolAsset, TreeConfigAnchor},
+    utils::transfer_compressed_nft,
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use mpl_bubblegum::utils::get_asset_id;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PoolWithdrawNftArgs {
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // The Keccak256 hash of the NFTs existing metadata (without the verified flag for the creator changed).
+    // The metadata is retrieved from off-chain data store.
+    data_hash: [u8; 32],
+    // The Keccak256 hash of the NFTs existing creators array (without the verified flag for the creator changed).
+    // The creators array is retrieved from off-chain data store.
+    creator_hash: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args:PoolWithdrawNftArgs)]
+pub struct PoolWithdrawNft<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=owner,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL.as_bytes(),
+          owner.key().as_ref(),
+          pool.uuid.as_ref(),
+      ],
+      bump=pool.bump)]
+    pool: Box<Account<'info, Pool>>,
+
+    /// CHECK: program_as_signer
+    #[account(
+      seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()],
+      bump)]
+    program_as_signer: UncheckedAccount<'info>, // escrow that currently owns the cNFT
+
+    // ==== cNFT transfer args ==== //
+    #[account(
+      mut,
+      seeds = [merkle_tree.key().as_ref()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+    )]
+    /// CHECK: This account is neither written to nor read from.
+    pub tree_authority: Account<'info, TreeConfigAnchor>,
+    // The account that contains the Merkle tree, initialized by create_tree.
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    #[account(
+      mut,
+      close=rent_payer,
+      has_one=pool,
+      has_one=rent_payer,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL_ASSET.as_bytes(),
+          merkle_tree.key().as_ref(),
+          args.index.to_le_bytes().as_ref(),
+      ],
+      bump=pool_asset.bump)]
+    pool_asset: Box<Account<'info, PoolAsset>>,
+
+    /// CHECK: checked against pool_asset, gets the pool_asset rent back.
+    #[account(mut)]
+    rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, PoolWithdrawNft<'info>>,
+    args: PoolWithdrawNftArgs,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    if get_asset_id(&ctx.accounts.merkle_tree.key(), args.nonce) != ctx.accounts.pool_asset.asset_id
+    {
+        return Err(ErrorCode::AssetIDMismatch.into());
+    }
+
+    let bump = ctx.bumps.program_as_signer;
+    let seeds = &[PREFIX.as_bytes(), SIGNER.as_bytes(), &[bump][..]];
+    transfer_compressed_nft(
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.program_as_signer.to_account_info(),
+        &ctx.accounts.program_as_signer.to_account_info(), // delegate
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        ctx.accounts.bubblegum_program.key(),
+        args.root,
+        args.data_hash,
+        args.creator_hash,
+        args.nonce,
+        args.index,
+        Some(seeds),
+    )?;
+
+    pool.nft_count = pool
+        .nft_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    pool.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::{constants::*, errors::ErrorCode, states::Pool};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PoolWithdrawSolArgs {
+    amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct PoolWithdrawSol<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=owner,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL.as_bytes(),
+          owner.key().as_ref(),
+          pool.uuid.as_ref(),
+      ],
+      bump=pool.bump)]
+    pool: Box<Account<'info, Pool>>,
+}
+
+pub fn handle(ctx: Context<PoolWithdrawSol>, args: PoolWithdrawSolArgs) -> Result<()> {
+    let pool = &ctx.accounts.pool.to_account_info();
+    let owner = &ctx.accounts.owner.to_account_info();
+
+    // The pool must stay rent exempt, close_pool returns the rest.
+    let available = pool
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(Pool::LEN));
+    if args.amount > available {
+        return Err(ErrorCode::InsufficientPoolBalance.into());
+    }
+
+    **pool.try_borrow_mut_lamports()? -= args.amount;
+    **owner.try_borrow_mut_lamports()? += args.amount;
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use solana_program::{program::invoke, system_instruction};
+
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Config, RentalListing},
+    utils::{
+        calculate_fees, load_collection_override, pay_creator_fees, pay_platform_fees,
+        take_accounts, verify_creators,
+    },
+};
+use anchor_lang::AnchorDeserialize;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RentAssetArgs {
+    periods: u32,
+    // Most the renter is willing to pay per period.
+    max_price_per_period: u64,
+
+    // Execute Sale Args
+    maker_fee_bp: i16,
+    taker_fee_bp: u16,
+    // Following arguments are required for paying creator royalties
+    creator_shares: Vec<u16>,
+    creator_verified: Vec<bool>,
+}
+
+#[derive(Accounts)]
+pub struct RentAsset<'info> {
+    #[account(mut)]
+    renter: Signer<'info>,
+    /// CHECK: owner checked in rental_listing.
+    #[account(mut)]
+    owner: UncheckedAccount<'info>,
+    /// CHECK: meNotary constant
+    #[account(address = ME_NOTARY)]
+    notary: UncheckedAccount<'info>,
+    /// CHECK: that this matches hard-coded auction_house_treasury
+    #[account(mut, address = ME_TREASURY)]
+    platform_treasury: UncheckedAccount<'info>,
+    /// CHECK: program config, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()], bump)]
+    config: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+
+    #[account(
+      mut,
+      has_one=owner,
+      seeds=[
+          PREFIX.as_bytes(),
+          RENTAL.as_bytes(),
+          rental_listing.merkle_tree.as_ref(),
+          rental_listing.index.to_le_bytes().as_ref(),
+      ],
+      bump=rental_listing.bump)]
+    rental_listing: Box<Account<'info, RentalListing>>,
+
+    /// CHECK: override keyed by the asset's creator hash, may not be initialized.
+    #[account(
+      seeds=[
+          PREFIX.as_bytes(),
+          COLLECTION_OVERRIDE.as_bytes(),
+          rental_listing.creator_hash.as_ref(),
+      ],
+      bump)]
+    creator_hash_override: UncheckedAccount<'info>,
+    /// CHECK: override keyed by the asset's verified collection, may not be initialized.
+    #[account(
+      seeds=[
+          PREFIX.as_bytes(),
+          COLLECTION_OVERRIDE.as_bytes(),
+          rental_listing.collection.as_ref(),
+      ],
+      bump)]
+    collection_override: UncheckedAccount<'info>,
+}
+
+// Remaining accounts are the (optional) creator addresses, a (RoyaltyRedirect, payee) pair per
+// creator and the fee recipient accounts, the asset stays with the rental_listing.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, RentAsset<'info>>,
+    args: RentAssetArgs,
+) -> Result<()> {
+    let renter = &ctx.accounts.renter.to_account_info();
+    let owner = &ctx.accounts.owner.to_account_info();
+    let notary = &ctx.accounts.notary;
+    let system_program = &ctx.accounts.system_program;
+    let rental_listing = &mut ctx.accounts.rental_listing;
+
+    let now = Clock::get()?.unix_timestamp;
+    if rental_listing.is_rented(now) {
+        return Err(ErrorCode::AssetRented.into());
+    }
+    if args.periods == 0 || args.periods > rental_listing.max_periods {
+        return Err(ErrorCode::InvalidRental.into());
+    }
+    if rental_listing.price_per_period > args.max_price_per_period {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+    let rent = rental_listing
+        .price_per_period
+        .checked_mul(args.periods as u64)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    let duration = rental_listing
+        .period_seconds
+        .checked_mul(args.periods as i64)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    // The asset's override replaces the default royalty share and fees, with the collection
+    // verified at listing time.
+    let collection = rental_listing.collection;
+    let collection_override = load_collection_override(
+        &ctx.accounts.creator_hash_override,
+        rental_listing.creator_hash,
+        &ctx.accounts.collection_override,
+        Some(&collection).filter(|collection| **collection != Pubkey::default()),
+    )?;
+
+    // 1: Pay Creator Fees on the rent, against the creator hash verified at listing time.
+    let config = Config::load(&ctx.accounts.config)?;
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let creator_accounts = take_accounts(&mut remaining_accounts, args.creator_shares.len())?;
+    let royalty_redirects = take_accounts(&mut remaining_accounts, 2 * args.creator_shares.len())?;
+    let fee_recipient_accounts =
+        take_accounts(&mut remaining_accounts, config.fee_recipients.len())?;
+    verify_creators(
+        creator_accounts.iter(),
+        args.creator_shares.clone(),
+        args.creator_verified,
+        rental_listing.creator_hash,
+    )?;
+    pay_creator_fees(
+        &mut creator_accounts.iter(),
+        args.creator_shares,
+        royalty_redirects,
+        renter,
+        system_program,
+        rent,
+        collection_override.as_ref(),
+        rental_listing.seller_fee_basis_points,
+    )?;
+
+    // 2: Calculate fees
+    let fees = calculate_fees(
+        notary,
+        rent,
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        renter,
+        owner,
+        (DEFAULT_MAKER_FEE_BP, DEFAULT_TAKER_FEE_BP),
+        collection_override.as_ref(),
+        config.fee_cap(&spl_token::native_mint::ID),
+    )?;
+
+    // 3: Renter pays Owner
+    invoke(
+        &system_instruction::transfer(renter.key, owner.key, fees.seller_will_get_from_buyer),
+        &[
+            renter.clone(),
+            owner.clone(),
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    // 4. Renter pays Treasury, split between the configured fee recipients.
+    pay_platform_fees(
+        renter,
+        &ctx.accounts.platform_treasury.to_account_info(),
+        fee_recipient_accounts,
+        &config.fee_recipients,
+        system_program,
+        fees.total_platform_fee,
+    )?;
+
+    // The asset stays owned by the rental_listing, which now points at the renter.
+    rental_listing.renter = renter.key();
+    rental_listing.rental_end = now
+        .checked_add(duration)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    msg!(
+        "{{\"asset_id\":\"{}\",\"renter\":\"{}\",\"rental_end\":{},\"rent\":{},\"maker_fee\":{},\"taker_fee\":{},\"total_platform_fee\":{}}}",
+        rental_listing.asset_id,
+        rental_listing.renter,
+        rental_listing.rental_end,
+        rent,
+        fees.maker_fee,
+        fees.taker_fee,
+        fees.total_platform_fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, Loan, TreeConfigAnchor},
+    utils::transfer_compressed_nft,
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use solana_program::{program::invoke, system_instruction};
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RepayLoanArgs {
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+}
+
+#[derive(Accounts)]
+pub struct RepayLoan<'info> {
+    #[account(mut)]
+    borrower: Signer<'info>,
+    /// CHECK: lender checked in loan, receives the repayment.
+    #[account(mut)]
+    lender: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      close=borrower,
+      has_one=borrower,
+      has_one=lender,
+      seeds=[
+          PREFIX.as_bytes(),
+          LOAN.as_bytes(),
+          loan.merkle_tree.as_ref(),
+          loan.index.to_le_bytes().as_ref(),
+      ],
+      bump=loan.bump)]
+    loan: Box<Account<'info, Loan>>,
+
+    /// CHECK: program_as_signer
+    #[account(
+      seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()],
+      bump)]
+    program_as_signer: UncheckedAccount<'info>, // escrow that currently owns the cNFT
+
+    // ==== cNFT transfer args ==== //
+    #[account(
+      mut,
+      seeds = [merkle_tree.key().as_ref()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+    )]
+    /// CHECK: This account is neither written to nor read from.
+    pub tree_authority: Account<'info, TreeConfigAnchor>,
+    // The account that contains the Merkle tree, initialized by create_tree.
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut, address = loan.merkle_tree)]
+    merkle_tree: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+}
+
+// Pays back principal plus interest and returns the collateral. Remaining accounts are the
+// Merkle proof path.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, RepayLoan<'info>>,
+    args: RepayLoanArgs,
+) -> Result<()> {
+    let borrower = &ctx.accounts.borrower;
+    let loan = &ctx.accounts.loan;
+
+    if Clock::get()?.unix_timestamp > loan.due_at {
+        return Err(ErrorCode::LoanOverdue.into());
+    }
+
+    invoke(
+        &system_instruction::transfer(borrower.key, ctx.accounts.lender.key, loan.repay_amount),
+        &[
+            borrower.to_account_info(),
+            ctx.accounts.lender.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let bump = ctx.bumps.program_as_signer;
+    let seeds = &[PREFIX.as_bytes(), SIGNER.as_bytes(), &[bump][..]];
+    transfer_compressed_nft(
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.program_as_signer.to_account_info(),
+        &ctx.accounts.program_as_signer.to_account_info(), // delegate
+        &borrower.to_account_info(),
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        ctx.accounts.bubblegum_program.key(),
+        args.root,
+        loan.data_hash,
+        loan.creator_hash,
+        loan.nonce,
+        loan.index,
+        Some(seeds),
+    )?;
+
+    msg!(
+        "{{\"asset_id\":\"{}\",\"lender\":\"{}\",\"borrower\":\"{}\",\"repaid\":{}}}",
+        loan.asset_id,
+        loan.lender,
+        loan.borrower,
+        loan.repay_amount
+    );
+
+    Ok(())
+}
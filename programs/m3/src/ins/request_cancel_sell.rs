@@ -0,0 +1,50 @@
+use crate::{constants::*, errors::ErrorCode, states::SellerTradeState};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RequestCancelSellArgs {
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args:RequestCancelSellArgs)]
+pub struct RequestCancelSell<'info> {
+    // Listing owner
+    wallet: Signer<'info>,
+
+    /// CHECK: only used to derive the seller_trade_state.
+    merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      constraint= seller_trade_state.seller == wallet.key() @ ErrorCode::IncorrectOwner,
+      seeds=[
+          PREFIX.as_bytes(),
+          merkle_tree.key().as_ref(),
+          args.index.to_le_bytes().as_ref(),
+      ],
+      bump=seller_trade_state.bump)]
+    seller_trade_state: Box<Account<'info, SellerTradeState>>,
+}
+
+// Self-service cancel for when the notary is unavailable. The listing can't be bought from now
+// on, and the seller can withdraw the asset through cancel_sell once the cooldown has elapsed.
+pub fn handle(ctx: Context<RequestCancelSell>, _args: RequestCancelSellArgs) -> Result<()> {
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+    if seller_trade_state.is_locked() {
+        return Err(ErrorCode::ListingLocked.into());
+    }
+    if seller_trade_state.cancel_requested_at != 0 {
+        return Err(ErrorCode::CancelPending.into());
+    }
+    seller_trade_state.cancel_requested_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "{{\"asset_id\":\"{}\",\"cancel_requested_at\":{}}}",
+        seller_trade_state.asset_id,
+        seller_trade_state.cancel_requested_at
+    );
+
+    Ok(())
+}
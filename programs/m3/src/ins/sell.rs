@@ -1,11 +1,12 @@
 use crate::{
     constants::*,
     errors::ErrorCode,
-    states::{BubblegumProgram, SellerTradeState, TreeConfigAnchor},
+    states::{BubblegumProgram, SellerTradeState, TreeConfigAnchor, UserState},
     utils::transfer_compressed_nft,
 };
 use anchor_lang::{prelude::*, AnchorDeserialize, Discriminator};
 use mpl_bubblegum::utils::get_asset_id;
+use solana_program::{program::invoke, system_instruction};
 use spl_account_compression::{program::SplAccountCompression, Noop};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -30,6 +31,13 @@ pub struct SellArgs {
     buyer_price: u64,
     // The mint of the SPL token used to pay for the NFT.
     payment_mint: Pubkey,
+    // Account receiving the sale proceeds instead of the seller, e.g. a multisig vault.
+    payout_address: Option<Pubkey>,
+    // Share of a defaulted layaway deposit the seller keeps, None doesn't allow layaways.
+    layaway_penalty_bp: Option<u16>,
+    // Delay before a price increase on this listing takes effect, so a reprice can't land in the
+    // same slot as a buy. Price decreases apply immediately. A reprice can't shorten it.
+    price_increase_delay_seconds: i64,
 }
 
 #[derive(Accounts)]
@@ -84,6 +92,10 @@ pub struct Sell<'info> {
       space=SellerTradeState::LEN,
       bump)]
     seller_trade_state: Box<Account<'info, SellerTradeState>>,
+
+    /// CHECK: wallet's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), wallet.key().as_ref()], bump)]
+    seller_user_state: UncheckedAccount<'info>,
 }
 
 pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Sell<'info>>, args: SellArgs) -> Result<()> {
@@ -103,10 +115,25 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Sell<'info>>, args: SellArg
     if args.buyer_price > MAX_PRICE || args.buyer_price == 0 {
         return Err(ErrorCode::InvalidPrice.into());
     }
+    if args.layaway_penalty_bp.is_some_and(|bp| bp > 10000) {
+        return Err(ErrorCode::InvalidLayaway.into());
+    }
+    if !(0..=MAX_PRICE_INCREASE_DELAY_SECONDS).contains(&args.price_increase_delay_seconds) {
+        return Err(ErrorCode::InvalidPriceIncreaseDelay.into());
+    }
 
     let asset_id = get_asset_id(&merkle_tree.key(), args.nonce);
+    let now = Clock::get()?.unix_timestamp;
     let is_new_listing = seller_trade_state.asset_id == Pubkey::default();
 
+    // A sponsor paying the listing rent signs as the first remaining account, ahead of the Merkle
+    // proof path whose accounts never sign. The wallet fronts the rent and the sponsor pays it
+    // back, the sponsor then gets the rent when the listing closes.
+    let (rent_payer, proof_path) = match ctx.remaining_accounts.split_first() {
+        Some((rent_payer, proof_path)) if rent_payer.is_signer => (Some(rent_payer), proof_path),
+        _ => (None, ctx.remaining_accounts),
+    };
+
     // Transfer the NFT to M3 Program if seller_Trade_state was just instantiated
     if is_new_listing {
         msg!(
@@ -122,7 +149,7 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Sell<'info>>, args: SellArg
             &ctx.accounts.log_wrapper,
             &ctx.accounts.compression_program,
             &ctx.accounts.system_program,
-            ctx.remaining_accounts,
+            proof_path,
             ctx.accounts.bubblegum_program.key(),
             args.root,
             args.data_hash,
@@ -131,7 +158,25 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Sell<'info>>, args: SellArg
             args.index,
             None, // signer passed through from ctx
         )?;
-        seller_trade_state.created_at = Clock::get()?.unix_timestamp;
+        seller_trade_state.created_at = now;
+        seller_trade_state.rent_payer = wallet.key();
+        if let Some(rent_payer) = rent_payer {
+            invoke(
+                &system_instruction::transfer(
+                    rent_payer.key,
+                    wallet.key,
+                    seller_trade_state_clone.lamports(),
+                ),
+                &[
+                    rent_payer.clone(),
+                    wallet.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+            seller_trade_state.rent_payer = rent_payer.key();
+        }
+        seller_trade_state.buyer_price = args.buyer_price;
+        seller_trade_state.price_increase_delay_seconds = args.price_increase_delay_seconds;
     } else {
         if seller_trade_state.seller != wallet.key() {
             msg!(
@@ -151,17 +196,56 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Sell<'info>>, args: SellArg
             );
             return Err(ErrorCode::AssetIDMismatch.into());
         }
-        msg!("Updating price to: {}", args.buyer_price);
+        // A listing cancelled with bump_nonce stays cancelled, it can only be withdrawn.
+        if seller_trade_state.seller_nonce
+            < UserState::current_nonce(&ctx.accounts.seller_user_state)?
+        {
+            return Err(ErrorCode::StaleNonce.into());
+        }
+        // The price and terms are fixed while a layaway buyer holds the lock.
+        if seller_trade_state.is_locked() {
+            return Err(ErrorCode::ListingLocked.into());
+        }
+        // Price increases only take effect after the listing's delay, decreases apply immediately.
+        seller_trade_state.apply_pending_price(now);
+        let delay = seller_trade_state
+            .price_increase_delay_seconds
+            .max(args.price_increase_delay_seconds);
+        seller_trade_state.price_increase_delay_seconds = delay;
+        if args.buyer_price > seller_trade_state.buyer_price && delay > 0 {
+            seller_trade_state.pending_buyer_price = args.buyer_price;
+            seller_trade_state.pending_price_effective_at = now + delay;
+            msg!(
+                "Updating price to: {} at {}",
+                args.buyer_price,
+                seller_trade_state.pending_price_effective_at
+            );
+        } else {
+            seller_trade_state.buyer_price = args.buyer_price;
+            seller_trade_state.pending_buyer_price = 0;
+            seller_trade_state.pending_price_effective_at = 0;
+            msg!("Updating price to: {}", args.buyer_price);
+        }
+        seller_trade_state.price_version = seller_trade_state
+            .price_version
+            .checked_add(1)
+            .ok_or(ErrorCode::NumericalOverflow)?;
     }
 
+    // Listing again withdraws any pending cancel request.
+    seller_trade_state.cancel_requested_at = 0;
+
     seller_trade_state.seller = wallet.key();
     seller_trade_state.seller_referral = seller_referral.key();
-    seller_trade_state.buyer_price = args.buyer_price;
     seller_trade_state.asset_id = asset_id;
     seller_trade_state.bump = bump;
     seller_trade_state.merkle_tree = ctx.accounts.merkle_tree.key();
     seller_trade_state.index = args.index;
-    seller_trade_state.updated_at = Clock::get()?.unix_timestamp;
+    seller_trade_state.updated_at = now;
+    seller_trade_state.seller_nonce = UserState::current_nonce(&ctx.accounts.seller_user_state)?;
+    seller_trade_state.payout_address = args.payout_address.unwrap_or_default();
+    seller_trade_state.layaway_enabled = args.layaway_penalty_bp.is_some();
+    seller_trade_state.layaway_penalty_bp = args.layaway_penalty_bp.unwrap_or_default();
 
     Ok(())
 }
@@ -0,0 +1,148 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, BundleItem, BundleTradeState, UserState},
+    utils::{take_accounts, transfer_compressed_nft},
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use mpl_bubblegum::utils::get_asset_id;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SellBundleItemArgs {
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // The Keccak256 hash of the NFTs existing metadata (without the verified flag for the creator changed).
+    // The metadata is retrieved from off-chain data store.
+    data_hash: [u8; 32],
+    // The Keccak256 hash of the NFTs existing creators array (without the verified flag for the creator changed).
+    // The creators array is retrieved from off-chain data store.
+    creator_hash: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+    // Number of proof accounts for this asset in remaining_accounts.
+    proof_len: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SellBundleArgs {
+    // Random key so a seller can have several bundles.
+    bundle_id: Pubkey,
+    // Price of the whole bundle in the payment_mint.
+    buyer_price: u64,
+    // The mint of the SPL token used to pay for the bundle.
+    payment_mint: Pubkey,
+    items: Vec<SellBundleItemArgs>,
+}
+
+#[derive(Accounts)]
+#[instruction(args:SellBundleArgs)]
+pub struct SellBundle<'info> {
+    // Listing owner
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: program_as_signer
+    #[account(
+      seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()],
+      bump)]
+    program_as_signer: UncheckedAccount<'info>, // escrow to hold the ownership of the cnfts
+
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    /// CHECK: seller_referral - not sure we need to check this
+    seller_referral: UncheckedAccount<'info>,
+
+    #[account(
+      init,
+      payer=wallet,
+      seeds=[
+          PREFIX.as_bytes(),
+          BUNDLE.as_bytes(),
+          wallet.key().as_ref(),
+          args.bundle_id.as_ref(),
+      ],
+      space=BundleTradeState::LEN,
+      bump)]
+    bundle_trade_state: Box<Account<'info, BundleTradeState>>,
+
+    /// CHECK: wallet's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), wallet.key().as_ref()], bump)]
+    seller_user_state: UncheckedAccount<'info>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellBundle<'info>>,
+    args: SellBundleArgs,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+
+    if args.items.len() < 2 || args.items.len() > MAX_BUNDLE_SIZE {
+        return Err(ErrorCode::InvalidBundleSize.into());
+    }
+    if args.buyer_price > MAX_PRICE || args.buyer_price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+
+    // Remaining accounts are, for each item: tree_authority, merkle_tree, leaf_delegate and the
+    // Merkle proof path.
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let mut items = Vec::with_capacity(args.items.len());
+    for item in args.items.iter() {
+        let item_accounts = take_accounts(&mut remaining_accounts, 3)?;
+        let proof_path = take_accounts(&mut remaining_accounts, item.proof_len as usize)?;
+        let (tree_authority, merkle_tree, leaf_delegate) =
+            (&item_accounts[0], &item_accounts[1], &item_accounts[2]);
+
+        transfer_compressed_nft(
+            tree_authority,
+            &wallet.to_account_info(),
+            leaf_delegate,
+            &ctx.accounts.program_as_signer.to_account_info(),
+            merkle_tree,
+            &ctx.accounts.log_wrapper,
+            &ctx.accounts.compression_program,
+            &ctx.accounts.system_program,
+            proof_path,
+            ctx.accounts.bubblegum_program.key(),
+            item.root,
+            item.data_hash,
+            item.creator_hash,
+            item.nonce,
+            item.index,
+            None, // signer passed through from ctx
+        )?;
+
+        items.push(BundleItem {
+            merkle_tree: merkle_tree.key(),
+            index: item.index,
+            asset_id: get_asset_id(merkle_tree.key, item.nonce),
+        });
+    }
+
+    let bundle_trade_state = &mut ctx.accounts.bundle_trade_state;
+    bundle_trade_state.seller = wallet.key();
+    bundle_trade_state.seller_referral = ctx.accounts.seller_referral.key();
+    bundle_trade_state.buyer_price = args.buyer_price;
+    bundle_trade_state.payment_mint = args.payment_mint;
+    bundle_trade_state.bump = ctx.bumps.bundle_trade_state;
+    bundle_trade_state.bundle_id = args.bundle_id;
+    bundle_trade_state.seller_nonce = UserState::current_nonce(&ctx.accounts.seller_user_state)?;
+    bundle_trade_state.created_at = Clock::get()?.unix_timestamp;
+    bundle_trade_state.updated_at = bundle_trade_state.created_at;
+    bundle_trade_state.items = items;
+
+    Ok(())
+}
@@ -0,0 +1,292 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, Config, Pool, PoolAsset, TraderStats, TreeConfigAnchor},
+    utils::{
+        assert_verified_collection, calculate_fees, curve_price_down, decode_metadata_args,
+        get_lp_fee, load_collection_override, pay_creator_fees, pay_platform_fees, take_accounts,
+        transfer_compressed_nft, verified_collection, verify_creators,
+    },
+};
+use anchor_lang::AnchorDeserialize;
+use mpl_bubblegum::utils::get_asset_id;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SellIntoPoolArgs {
+    // Least the seller is willing to get from the pool, after the LP fee.
+    min_price: u64,
+    // Pools are the maker side, only the taker fee applies.
+    taker_fee_bp: u16,
+    // Borsh serialized Bubblegum MetadataArgs of the asset, used to verify its collection
+    // and creators.
+    metadata_args: Vec<u8>,
+
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args:SellIntoPoolArgs)]
+pub struct SellIntoPool<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    /// CHECK: meNotary constant
+    #[account(address = ME_NOTARY)]
+    notary: UncheckedAccount<'info>,
+    /// CHECK: that this matches hard-coded auction_house_treasury
+    #[account(mut, address = ME_TREASURY)]
+    platform_treasury: UncheckedAccount<'info>,
+    /// CHECK: program config, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()], bump)]
+    config: UncheckedAccount<'info>,
+    /// CHECK: override keyed by the asset's creator hash, may not be initialized. Checked in
+    /// load_collection_override.
+    creator_hash_override: UncheckedAccount<'info>,
+    /// CHECK: override keyed by the asset's verified collection, may not be initialized. Checked
+    /// in load_collection_override.
+    collection_override: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL.as_bytes(),
+          pool.owner.as_ref(),
+          pool.uuid.as_ref(),
+      ],
+      bump=pool.bump)]
+    pool: Box<Account<'info, Pool>>,
+
+    #[account(
+      init,
+      payer=seller,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL_ASSET.as_bytes(),
+          merkle_tree.key().as_ref(),
+          args.index.to_le_bytes().as_ref(),
+      ],
+      space=PoolAsset::LEN,
+      bump)]
+    pool_asset: Box<Account<'info, PoolAsset>>,
+
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>, // escrow to hold the ownership of the cnft
+
+    // ==== cNFT transfer args ==== //
+    #[account(
+      mut,
+      seeds = [merkle_tree.key().as_ref()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+    )]
+    /// CHECK: This account is neither written to nor read from.
+    pub tree_authority: Account<'info, TreeConfigAnchor>,
+    // The NFT delegate. Transfers must be signed by either the NFT owner or NFT delegate.
+    /// CHECK: This account is checked in the Bubblegum transfer instruction
+    leaf_delegate: UncheckedAccount<'info>,
+    // The account that contains the Merkle tree, initialized by create_tree.
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    #[account(
+      init_if_needed,
+      payer=seller,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), seller.key().as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    seller_stats: Box<Account<'info, TraderStats>>,
+
+    #[account(
+      init_if_needed,
+      payer=seller,
+      seeds=[PREFIX.as_bytes(), TRADER_STATS.as_bytes(), pool.owner.as_ref()],
+      space=TraderStats::LEN,
+      bump)]
+    pool_owner_stats: Box<Account<'info, TraderStats>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellIntoPool<'info>>,
+    args: SellIntoPoolArgs,
+) -> Result<()> {
+    let seller = &ctx.accounts.seller.to_account_info();
+    let pool_ai = &ctx.accounts.pool.to_account_info();
+    let notary = &ctx.accounts.notary;
+    let platform_treasury = &ctx.accounts.platform_treasury;
+    let system_program = &ctx.accounts.system_program;
+    let pool = &mut ctx.accounts.pool;
+
+    let (metadata, data_hash, creator_hash) = decode_metadata_args(&args.metadata_args)?;
+    assert_verified_collection(&metadata, &pool.collection)?;
+
+    let price = curve_price_down(pool.curve_type, pool.spot_price, pool.delta)?;
+    let lp_fee = get_lp_fee(price, pool.lp_fee_bp)?;
+    let pool_pays = price
+        .checked_sub(lp_fee)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    if pool_pays < args.min_price {
+        msg!(
+            "Pool price {} is below min price {}",
+            pool_pays,
+            args.min_price
+        );
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+    let available = pool_ai
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(Pool::LEN));
+    if pool_pays > available {
+        return Err(ErrorCode::InsufficientPoolBalance.into());
+    }
+
+    // The asset's override replaces the default royalty share and fees.
+    let collection_override = load_collection_override(
+        &ctx.accounts.creator_hash_override,
+        creator_hash,
+        &ctx.accounts.collection_override,
+        verified_collection(&metadata),
+    )?;
+
+    // Remaining accounts are 1. (Optional) creator addresses, 2. a (RoyaltyRedirect, payee) pair
+    // per creator, 3. fee recipient accounts and 4. Merkle proof path.
+    let creator_shares: Vec<u16> = metadata.creators.iter().map(|c| c.share as u16).collect();
+    let creator_verified: Vec<bool> = metadata.creators.iter().map(|c| c.verified).collect();
+    let config = Config::load(&ctx.accounts.config)?;
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let creator_accounts = take_accounts(&mut remaining_accounts, creator_shares.len())?;
+    let royalty_redirects = take_accounts(&mut remaining_accounts, 2 * creator_shares.len())?;
+    let fee_recipient_accounts =
+        take_accounts(&mut remaining_accounts, config.fee_recipients.len())?;
+    let proof_path = remaining_accounts;
+
+    // 1. Transfer NFT to the pool
+    transfer_compressed_nft(
+        &ctx.accounts.tree_authority.to_account_info(),
+        seller,
+        &ctx.accounts.leaf_delegate.to_account_info(), // delegate
+        &ctx.accounts.program_as_signer.to_account_info(),
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        &ctx.accounts.system_program,
+        proof_path,
+        ctx.accounts.bubblegum_program.key(),
+        args.root,
+        data_hash,
+        creator_hash,
+        args.nonce,
+        args.index,
+        None, // signer passed through from ctx
+    )?;
+
+    // 2. Pool pays Seller, keeping the LP fee
+    **pool_ai.try_borrow_mut_lamports()? -= pool_pays;
+    **seller.try_borrow_mut_lamports()? += pool_pays;
+
+    // 3. Seller pays Creator Fees out of the proceeds
+    verify_creators(
+        creator_accounts.iter(),
+        creator_shares.clone(),
+        creator_verified,
+        creator_hash,
+    )?;
+    pay_creator_fees(
+        &mut creator_accounts.iter(),
+        creator_shares,
+        royalty_redirects,
+        seller,
+        system_program,
+        price,
+        collection_override.as_ref(),
+        metadata.seller_fee_basis_points,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.seller_stats.roll(now);
+    ctx.accounts.pool_owner_stats.roll(now);
+    let tier_fee_bp = config.tier_fee_bp(
+        ctx.accounts.pool_owner_stats.rolling_volume(),
+        ctx.accounts.seller_stats.rolling_volume(),
+    );
+
+    // 4. Seller pays Treasury, split between the configured fee recipients.
+    let fees = calculate_fees(
+        notary,
+        price,
+        0,
+        args.taker_fee_bp,
+        seller,
+        pool_ai,
+        tier_fee_bp,
+        collection_override.as_ref(),
+        config.fee_cap(&spl_token::native_mint::ID),
+    )?;
+    pay_platform_fees(
+        seller,
+        &platform_treasury.to_account_info(),
+        fee_recipient_accounts,
+        &config.fee_recipients,
+        system_program,
+        fees.total_platform_fee,
+    )?;
+
+    // 5. Track the asset in the pool and move the curve down
+    let pool_asset = &mut ctx.accounts.pool_asset;
+    pool_asset.pool = pool.key();
+    pool_asset.asset_id = get_asset_id(&ctx.accounts.merkle_tree.key(), args.nonce);
+    pool_asset.merkle_tree = ctx.accounts.merkle_tree.key();
+    pool_asset.index = args.index;
+    pool_asset.rent_payer = seller.key();
+    pool_asset.bump = ctx.bumps.pool_asset;
+
+    pool.spot_price = price;
+    pool.nft_count = pool
+        .nft_count
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    pool.updated_at = now;
+
+    // Trading against yourself doesn't count towards the fee tiers.
+    let volume = if seller.key() != pool.owner { price } else { 0 };
+    let seller_bump = ctx.bumps.seller_stats;
+    ctx.accounts
+        .seller_stats
+        .record_fill(seller.key(), seller_bump, now, volume);
+    let pool_owner_bump = ctx.bumps.pool_owner_stats;
+    ctx.accounts
+        .pool_owner_stats
+        .record_fill(pool.owner, pool_owner_bump, now, volume);
+
+    msg!(
+        "{{\"price\":{},\"lp_fee\":{},\"taker_fee\":{},\"total_platform_fee\":{},\"spot_price\":{}}}",
+        price,
+        lp_fee,
+        fees.taker_fee,
+        fees.total_platform_fee,
+        pool.spot_price
+    );
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{CollectionOverride, Config, OverrideKind},
+    utils::assert_valid_fees_bp,
+};
+use anchor_lang::prelude::*;
+use std::convert::TryFrom;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetCollectionOverrideArgs {
+    // Verified collection mint, or the creator hash as a pubkey.
+    key: Pubkey,
+    kind: OverrideKind,
+    maker_fee_bp: i16,
+    taker_fee_bp: u16,
+    creator_royalty_bp: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(args:SetCollectionOverrideArgs)]
+pub struct SetCollectionOverride<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+      has_one=authority,
+      seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()],
+      bump=config.bump)]
+    config: Box<Account<'info, Config>>,
+
+    #[account(
+      init_if_needed,
+      payer=authority,
+      seeds=[PREFIX.as_bytes(), COLLECTION_OVERRIDE.as_bytes(), args.key.as_ref()],
+      space=CollectionOverride::LEN,
+      bump)]
+    collection_override: Box<Account<'info, CollectionOverride>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<SetCollectionOverride>, args: SetCollectionOverrideArgs) -> Result<()> {
+    let taker_fee_bp =
+        i16::try_from(args.taker_fee_bp).map_err(|_| ErrorCode::InvalidMakerTakerFee)?;
+    assert_valid_fees_bp(args.maker_fee_bp, taker_fee_bp)?;
+    if args.creator_royalty_bp > 10000 {
+        return Err(ErrorCode::InvalidCollectionOverride.into());
+    }
+
+    let collection_override = &mut ctx.accounts.collection_override;
+    // The kind is part of what the key means, it can't change once created.
+    if collection_override.key == args.key && collection_override.kind != args.kind {
+        return Err(ErrorCode::InvalidCollectionOverride.into());
+    }
+    collection_override.key = args.key;
+    collection_override.kind = args.kind;
+    collection_override.bump = ctx.bumps.collection_override;
+    collection_override.maker_fee_bp = args.maker_fee_bp;
+    collection_override.taker_fee_bp = args.taker_fee_bp;
+    collection_override.creator_royalty_bp = args.creator_royalty_bp;
+    collection_override.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "{{\"collection_override\":\"{}\",\"key\":\"{}\",\"kind\":\"{:?}\",\"maker_fee_bp\":{},\"taker_fee_bp\":{},\"creator_royalty_bp\":{}}}",
+        collection_override.key(),
+        args.key,
+        args.kind,
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        args.creator_royalty_bp
+    );
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::{constants::*, states::RoyaltyRedirect};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRoyaltyRedirectArgs {
+    // Receives the creator's royalties from now on, the creator itself to undo the redirect.
+    destination: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct SetRoyaltyRedirect<'info> {
+    // Any creator in an asset's creators, verified or not. Can be a PDA signing through CPI.
+    creator: Signer<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+      init_if_needed,
+      payer=payer,
+      seeds=[PREFIX.as_bytes(), ROYALTY_REDIRECT.as_bytes(), creator.key().as_ref()],
+      space=RoyaltyRedirect::LEN,
+      bump)]
+    royalty_redirect: Box<Account<'info, RoyaltyRedirect>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<SetRoyaltyRedirect>, args: SetRoyaltyRedirectArgs) -> Result<()> {
+    let royalty_redirect = &mut ctx.accounts.royalty_redirect;
+    royalty_redirect.creator = ctx.accounts.creator.key();
+    royalty_redirect.destination = args.destination;
+    royalty_redirect.bump = ctx.bumps.royalty_redirect;
+    royalty_redirect.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "{{\"royalty_redirect\":\"{}\",\"creator\":\"{}\",\"destination\":\"{}\"}}",
+        royalty_redirect.key(),
+        royalty_redirect.creator,
+        royalty_redirect.destination
+    );
+
+    Ok(())
+}
@@ -0,0 +1,209 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{BubblegumProgram, BuyerEscrow, Loan, LoanOffer, TreeConfigAnchor, UserState},
+    utils::{assert_verified_collection, decode_metadata_args, transfer_compressed_nft},
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use mpl_bubblegum::utils::get_asset_id;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TakeLoanArgs {
+    // Loan terms the borrower accepts, so the lender can't swap the offer out from under them.
+    min_principal: u64,
+    max_interest_bp: u16,
+    min_duration_seconds: i64,
+
+    // Borsh serialized Bubblegum MetadataArgs of the collateral, used to verify its collection.
+    metadata_args: Vec<u8>,
+
+    // === cNFT transfer args === //
+    // The Merkle root for the tree. Can be retrieved from off-chain data store.
+    root: [u8; 32],
+    // A nonce ("number used once") value used to make the Merkle tree leaves unique.
+    // This is the value of num_minted for the tree stored in the TreeConfig account at the time the NFT was minted.
+    // The unique value for each asset can be retrieved from off-chain data store.
+    nonce: u64,
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args:TakeLoanArgs)]
+pub struct TakeLoan<'info> {
+    #[account(mut)]
+    borrower: Signer<'info>,
+    /// CHECK: lender checked in loan_offer, gets the offer's rent back.
+    #[account(mut)]
+    lender: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      close=lender,
+      has_one=lender,
+      seeds=[
+          PREFIX.as_bytes(),
+          LOAN_OFFER.as_bytes(),
+          lender.key().as_ref(),
+          loan_offer.offer_id.as_ref(),
+      ],
+      bump=loan_offer.bump)]
+    loan_offer: Box<Account<'info, LoanOffer>>,
+
+    // Pays the principal.
+    #[account(
+      mut,
+      seeds=[PREFIX.as_bytes(), BUYER_ESCROW.as_bytes(), lender.key().as_ref()],
+      bump=lender_escrow.bump)]
+    lender_escrow: Box<Account<'info, BuyerEscrow>>,
+
+    /// CHECK: lender's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), lender.key().as_ref()], bump)]
+    lender_user_state: UncheckedAccount<'info>,
+
+    /// CHECK: program_as_signer
+    #[account(
+      seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()],
+      bump)]
+    program_as_signer: UncheckedAccount<'info>, // escrow to hold the ownership of the cnft
+
+    // ==== cNFT transfer args ==== //
+    #[account(
+      mut,
+      seeds = [merkle_tree.key().as_ref()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+    )]
+    /// CHECK: This account is neither written to nor read from.
+    pub tree_authority: Account<'info, TreeConfigAnchor>,
+    // The NFT delegate. Transfers must be signed by either the NFT owner or NFT delegate.
+    /// CHECK: This account is checked in the Bubblegum transfer instruction
+    leaf_delegate: UncheckedAccount<'info>,
+    // The account that contains the Merkle tree, initialized by create_tree.
+    /// CHECK: This account is modified in the downstream Bubblegum program
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+    // Used by bubblegum for logging (CPI)
+    log_wrapper: Program<'info, Noop>,
+
+    bubblegum_program: Program<'info, BubblegumProgram>,
+
+    system_program: Program<'info, System>,
+
+    // The Solana Program Library spl-account-compression program ID.
+    compression_program: Program<'info, SplAccountCompression>,
+
+    #[account(
+      init,
+      payer=borrower,
+      seeds=[
+          PREFIX.as_bytes(),
+          LOAN.as_bytes(),
+          merkle_tree.key().as_ref(),
+          args.index.to_le_bytes().as_ref(),
+      ],
+      space=Loan::LEN,
+      bump)]
+    loan: Box<Account<'info, Loan>>,
+}
+
+// Escrows the collateral and pays the principal to the borrower. Remaining accounts are the
+// Merkle proof path.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, TakeLoan<'info>>,
+    args: TakeLoanArgs,
+) -> Result<()> {
+    let borrower = &ctx.accounts.borrower;
+    let loan_offer = &ctx.accounts.loan_offer;
+
+    // Offers made before the lender bumped their nonce are cancelled.
+    if loan_offer.lender_nonce < UserState::current_nonce(&ctx.accounts.lender_user_state)? {
+        return Err(ErrorCode::StaleNonce.into());
+    }
+
+    if loan_offer.principal < args.min_principal
+        || loan_offer.interest_bp > args.max_interest_bp
+        || loan_offer.duration_seconds < args.min_duration_seconds
+    {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+
+    let principal = loan_offer.principal;
+    let lender_escrow = &ctx.accounts.lender_escrow.to_account_info();
+    let available = lender_escrow
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(BuyerEscrow::LEN));
+    if principal > available {
+        msg!(
+            "Lender escrow short: {{\"needed\":{},\"available\":{}}}",
+            principal,
+            available
+        );
+        return Err(ErrorCode::InsufficientEscrowBalance.into());
+    }
+
+    let (metadata, data_hash, creator_hash) = decode_metadata_args(&args.metadata_args)?;
+    assert_verified_collection(&metadata, &loan_offer.collection)?;
+
+    transfer_compressed_nft(
+        &ctx.accounts.tree_authority.to_account_info(),
+        &borrower.to_account_info(),
+        &ctx.accounts.leaf_delegate.to_account_info(), // delegate
+        &ctx.accounts.program_as_signer.to_account_info(),
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        ctx.accounts.bubblegum_program.key(),
+        args.root,
+        data_hash,
+        creator_hash,
+        args.nonce,
+        args.index,
+        None, // signer passed through from ctx
+    )?;
+
+    // The lender's escrow pays the principal, the offer's rent goes back to the lender.
+    let interest = (principal as u128)
+        .checked_mul(loan_offer.interest_bp as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::NumericalOverflow)? as u64;
+    let now = Clock::get()?.unix_timestamp;
+    let due_at = now
+        .checked_add(loan_offer.duration_seconds)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    **lender_escrow.try_borrow_mut_lamports()? -= principal;
+    **borrower.to_account_info().try_borrow_mut_lamports()? += principal;
+
+    let loan = &mut ctx.accounts.loan;
+    loan.lender = loan_offer.lender;
+    loan.borrower = borrower.key();
+    loan.asset_id = get_asset_id(&ctx.accounts.merkle_tree.key(), args.nonce);
+    loan.merkle_tree = ctx.accounts.merkle_tree.key();
+    loan.index = args.index;
+    loan.nonce = args.nonce;
+    loan.data_hash = data_hash;
+    loan.creator_hash = creator_hash;
+    loan.bump = ctx.bumps.loan;
+    loan.principal = principal;
+    loan.repay_amount = principal
+        .checked_add(interest)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    loan.due_at = due_at;
+    loan.created_at = now;
+
+    msg!(
+        "{{\"asset_id\":\"{}\",\"lender\":\"{}\",\"borrower\":\"{}\",\"principal\":{},\"repay_amount\":{},\"due_at\":{}}}",
+        loan.asset_id,
+        loan.lender,
+        loan.borrower,
+        loan.principal,
+        loan.repay_amount,
+        loan.due_at
+    );
+
+    Ok(())
+}
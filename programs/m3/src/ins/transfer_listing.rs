@@ -0,0 +1,78 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{SellerTradeState, UserState},
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransferListingArgs {
+    // The index of the leaf in the merkle tree. Can be retrieved from off-chain store.
+    index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args:TransferListingArgs)]
+pub struct TransferListing<'info> {
+    // Current listing owner
+    wallet: Signer<'info>,
+    // New listing owner
+    new_wallet: Signer<'info>,
+
+    /// CHECK: only used to derive the seller_trade_state.
+    merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      constraint= seller_trade_state.seller == wallet.key() @ ErrorCode::IncorrectOwner,
+      seeds=[
+          PREFIX.as_bytes(),
+          merkle_tree.key().as_ref(),
+          args.index.to_le_bytes().as_ref(),
+      ],
+      bump=seller_trade_state.bump)]
+    seller_trade_state: Box<Account<'info, SellerTradeState>>,
+
+    /// CHECK: wallet's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), wallet.key().as_ref()], bump)]
+    wallet_user_state: UncheckedAccount<'info>,
+
+    /// CHECK: new_wallet's UserState, may not be initialized yet.
+    #[account(seeds=[PREFIX.as_bytes(), USER.as_bytes(), new_wallet.key().as_ref()], bump)]
+    new_wallet_user_state: UncheckedAccount<'info>,
+}
+
+// Moves a listing to another wallet, e.g. during a wallet migration, without taking the asset out
+// of escrow. Price, created_at and the rent payer are kept, the payout address is reset so
+// proceeds go to the new wallet.
+pub fn handle(ctx: Context<TransferListing>, _args: TransferListingArgs) -> Result<()> {
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+    let new_wallet = &ctx.accounts.new_wallet;
+
+    // A listing cancelled with bump_nonce can't be revived by moving it to another wallet.
+    if seller_trade_state.seller_nonce < UserState::current_nonce(&ctx.accounts.wallet_user_state)?
+    {
+        return Err(ErrorCode::StaleNonce.into());
+    }
+    // The payout address of a locked listing is part of the layaway terms.
+    if seller_trade_state.is_locked() {
+        return Err(ErrorCode::ListingLocked.into());
+    }
+
+    msg!(
+        "Transferring listing {} from {} to {}",
+        seller_trade_state.asset_id,
+        seller_trade_state.seller,
+        new_wallet.key()
+    );
+
+    // Pin the rent payer before changing the seller, it defaults to the seller.
+    seller_trade_state.rent_payer = seller_trade_state.rent_payer();
+    seller_trade_state.seller = new_wallet.key();
+    seller_trade_state.seller_nonce =
+        UserState::current_nonce(&ctx.accounts.new_wallet_user_state)?;
+    seller_trade_state.payout_address = Pubkey::default();
+    seller_trade_state.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Config, FeeCap},
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateFeeCapsArgs {
+    // At most one cap per payment_mint, mints without a cap use the bp fees as is.
+    fee_caps: Vec<FeeCap>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFeeCaps<'info> {
+    authority: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=authority,
+      seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()],
+      bump=config.bump)]
+    config: Box<Account<'info, Config>>,
+}
+
+pub fn handle(ctx: Context<UpdateFeeCaps>, args: UpdateFeeCapsArgs) -> Result<()> {
+    let fee_caps = args.fee_caps;
+
+    if fee_caps.len() > MAX_FEE_CAPS {
+        return Err(ErrorCode::InvalidFeeCaps.into());
+    }
+    for (i, fee_cap) in fee_caps.iter().enumerate() {
+        if fee_cap.min_fee > fee_cap.max_fee
+            || fee_cap.max_fee > MAX_PRICE
+            || fee_caps[..i]
+                .iter()
+                .any(|other| other.payment_mint == fee_cap.payment_mint)
+        {
+            return Err(ErrorCode::InvalidFeeCaps.into());
+        }
+        msg!(
+            "{{\"payment_mint\":\"{}\",\"min_fee\":{},\"max_fee\":{}}}",
+            fee_cap.payment_mint,
+            fee_cap.min_fee,
+            fee_cap.max_fee
+        );
+    }
+    ctx.accounts.config.fee_caps = fee_caps;
+
+    Ok(())
+}
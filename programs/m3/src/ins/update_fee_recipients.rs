@@ -0,0 +1,51 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Config, FeeRecipient},
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateFeeRecipientsArgs {
+    // Shares must add up to 10,000 bp, an empty list sends all platform fees to ME_TREASURY.
+    fee_recipients: Vec<FeeRecipient>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFeeRecipients<'info> {
+    authority: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=authority,
+      seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()],
+      bump=config.bump)]
+    config: Box<Account<'info, Config>>,
+}
+
+pub fn handle(ctx: Context<UpdateFeeRecipients>, args: UpdateFeeRecipientsArgs) -> Result<()> {
+    let fee_recipients = args.fee_recipients;
+
+    let total_share_bp = fee_recipients.iter().try_fold(0u16, |total, recipient| {
+        total.checked_add(recipient.share_bp)
+    });
+    if fee_recipients.len() > MAX_FEE_RECIPIENTS
+        || (!fee_recipients.is_empty() && total_share_bp != Some(10000))
+        || fee_recipients
+            .iter()
+            .any(|recipient| recipient.share_bp == 0)
+    {
+        return Err(ErrorCode::InvalidFeeRecipients.into());
+    }
+
+    for recipient in fee_recipients.iter() {
+        msg!(
+            "{{\"fee_recipient\":\"{}\",\"share_bp\":{}}}",
+            recipient.address,
+            recipient.share_bp
+        );
+    }
+    ctx.accounts.config.fee_recipients = fee_recipients;
+
+    Ok(())
+}
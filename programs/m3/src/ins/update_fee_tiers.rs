@@ -0,0 +1,51 @@
+use crate::{
+    constants::*,
+    errors::ErrorCode,
+    states::{Config, FeeTier},
+    utils::assert_valid_fees_bp,
+};
+use anchor_lang::prelude::*;
+use std::convert::TryFrom;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateFeeTiersArgs {
+    // Sorted by min_volume, an empty list falls back to the default fees.
+    fee_tiers: Vec<FeeTier>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFeeTiers<'info> {
+    authority: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=authority,
+      seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()],
+      bump=config.bump)]
+    config: Box<Account<'info, Config>>,
+}
+
+pub fn handle(ctx: Context<UpdateFeeTiers>, args: UpdateFeeTiersArgs) -> Result<()> {
+    let fee_tiers = args.fee_tiers;
+
+    if fee_tiers.len() > MAX_FEE_TIERS
+        || fee_tiers
+            .windows(2)
+            .any(|pair| pair[0].min_volume >= pair[1].min_volume)
+    {
+        return Err(ErrorCode::InvalidFeeTiers.into());
+    }
+    // The seller's and buyer's tiers can differ, so every maker/taker combination must be valid.
+    for maker_tier in fee_tiers.iter() {
+        for taker_tier in fee_tiers.iter() {
+            let taker_fee_bp = i16::try_from(taker_tier.taker_fee_bp)
+                .map_err(|_| ErrorCode::InvalidMakerTakerFee)?;
+            assert_valid_fees_bp(maker_tier.maker_fee_bp, taker_fee_bp)?;
+        }
+    }
+
+    msg!("Updating {} fee tiers", fee_tiers.len());
+    ctx.accounts.config.fee_tiers = fee_tiers;
+
+    Ok(())
+}
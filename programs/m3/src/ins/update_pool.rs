@@ -0,0 +1,44 @@
+use crate::{
+    constants::*,
+    states::{CurveType, Pool},
+    utils::assert_valid_pool_params,
+};
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePoolArgs {
+    curve_type: CurveType,
+    spot_price: u64,
+    delta: u64,
+    lp_fee_bp: u16,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePool<'info> {
+    owner: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=owner,
+      seeds=[
+          PREFIX.as_bytes(),
+          POOL.as_bytes(),
+          owner.key().as_ref(),
+          pool.uuid.as_ref(),
+      ],
+      bump=pool.bump)]
+    pool: Box<Account<'info, Pool>>,
+}
+
+pub fn handle(ctx: Context<UpdatePool>, args: UpdatePoolArgs) -> Result<()> {
+    assert_valid_pool_params(args.curve_type, args.spot_price, args.delta, args.lp_fee_bp)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.curve_type = args.curve_type;
+    pool.spot_price = args.spot_price;
+    pool.delta = args.delta;
+    pool.lp_fee_bp = args.lp_fee_bp;
+    pool.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
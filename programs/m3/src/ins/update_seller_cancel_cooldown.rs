@@ -0,0 +1,35 @@
+use crate::{constants::*, errors::ErrorCode, states::Config};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateSellerCancelCooldownArgs {
+    // Time a seller has to wait after request_cancel_sell before cancelling without the notary.
+    seller_cancel_cooldown_seconds: i64,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSellerCancelCooldown<'info> {
+    authority: Signer<'info>,
+
+    #[account(
+      mut,
+      has_one=authority,
+      seeds=[PREFIX.as_bytes(), CONFIG.as_bytes()],
+      bump=config.bump)]
+    config: Box<Account<'info, Config>>,
+}
+
+pub fn handle(
+    ctx: Context<UpdateSellerCancelCooldown>,
+    args: UpdateSellerCancelCooldownArgs,
+) -> Result<()> {
+    let cooldown = args.seller_cancel_cooldown_seconds;
+    if !(0..=MAX_SELLER_CANCEL_COOLDOWN_SECONDS).contains(&cooldown) {
+        return Err(ErrorCode::InvalidCancelCooldown.into());
+    }
+    ctx.accounts.config.seller_cancel_cooldown_seconds = cooldown;
+
+    msg!("{{\"seller_cancel_cooldown_seconds\":{}}}", cooldown);
+
+    Ok(())
+}
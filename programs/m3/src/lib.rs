@@ -22,14 +22,295 @@ pub mod m3 {
     pub fn buy_now<'info>(
         ctx: Context<'_, '_, '_, 'info, BuyNow<'info>>,
         args: BuyNowArgs,
-    ) -> Result<()> {
+    ) -> Result<u64> {
         ins::buy_now::handle(ctx, args)
     }
 
+    pub fn close_fee_override_receipt(ctx: Context<CloseFeeOverrideReceipt>) -> Result<()> {
+        ins::close_fee_override_receipt::handle(ctx)
+    }
+
     pub fn cancel_sell<'info>(
         ctx: Context<'_, '_, '_, 'info, CancelSell<'info>>,
         args: CancelSellArgs,
     ) -> Result<()> {
         ins::cancel_sell::handle(ctx, args)
     }
+
+    pub fn buy_signed_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuySignedOrder<'info>>,
+        args: BuySignedOrderArgs,
+    ) -> Result<()> {
+        ins::buy_signed_order::handle(ctx, args)
+    }
+
+    pub fn close_signed_order_receipt(ctx: Context<CloseSignedOrderReceipt>) -> Result<()> {
+        ins::close_signed_order_receipt::handle(ctx)
+    }
+
+    pub fn bump_nonce(ctx: Context<BumpNonce>) -> Result<()> {
+        ins::bump_nonce::handle(ctx)
+    }
+
+    pub fn request_cancel_sell(
+        ctx: Context<RequestCancelSell>,
+        args: RequestCancelSellArgs,
+    ) -> Result<()> {
+        ins::request_cancel_sell::handle(ctx, args)
+    }
+
+    pub fn init_config(ctx: Context<InitConfig>) -> Result<()> {
+        ins::init_config::handle(ctx)
+    }
+
+    pub fn update_seller_cancel_cooldown(
+        ctx: Context<UpdateSellerCancelCooldown>,
+        args: UpdateSellerCancelCooldownArgs,
+    ) -> Result<()> {
+        ins::update_seller_cancel_cooldown::handle(ctx, args)
+    }
+
+    pub fn create_pool(ctx: Context<CreatePool>, args: CreatePoolArgs) -> Result<()> {
+        ins::create_pool::handle(ctx, args)
+    }
+
+    pub fn update_pool(ctx: Context<UpdatePool>, args: UpdatePoolArgs) -> Result<()> {
+        ins::update_pool::handle(ctx, args)
+    }
+
+    pub fn pool_deposit_sol(ctx: Context<PoolDepositSol>, args: PoolDepositSolArgs) -> Result<()> {
+        ins::pool_deposit_sol::handle(ctx, args)
+    }
+
+    pub fn pool_withdraw_sol(
+        ctx: Context<PoolWithdrawSol>,
+        args: PoolWithdrawSolArgs,
+    ) -> Result<()> {
+        ins::pool_withdraw_sol::handle(ctx, args)
+    }
+
+    pub fn close_pool(ctx: Context<ClosePool>) -> Result<()> {
+        ins::close_pool::handle(ctx)
+    }
+
+    pub fn pool_deposit_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, PoolDepositNft<'info>>,
+        args: PoolDepositNftArgs,
+    ) -> Result<()> {
+        ins::pool_deposit_nft::handle(ctx, args)
+    }
+
+    pub fn pool_withdraw_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, PoolWithdrawNft<'info>>,
+        args: PoolWithdrawNftArgs,
+    ) -> Result<()> {
+        ins::pool_withdraw_nft::handle(ctx, args)
+    }
+
+    pub fn buy_from_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyFromPool<'info>>,
+        args: BuyFromPoolArgs,
+    ) -> Result<()> {
+        ins::buy_from_pool::handle(ctx, args)
+    }
+
+    pub fn sell_into_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellIntoPool<'info>>,
+        args: SellIntoPoolArgs,
+    ) -> Result<()> {
+        ins::sell_into_pool::handle(ctx, args)
+    }
+
+    pub fn sell_bundle<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellBundle<'info>>,
+        args: SellBundleArgs,
+    ) -> Result<()> {
+        ins::sell_bundle::handle(ctx, args)
+    }
+
+    pub fn buy_bundle<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyBundle<'info>>,
+        args: BuyBundleArgs,
+    ) -> Result<()> {
+        ins::buy_bundle::handle(ctx, args)
+    }
+
+    pub fn cancel_bundle<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelBundle<'info>>,
+        args: CancelBundleArgs,
+    ) -> Result<()> {
+        ins::cancel_bundle::handle(ctx, args)
+    }
+
+    pub fn create_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateSwap<'info>>,
+        args: CreateSwapArgs,
+    ) -> Result<()> {
+        ins::create_swap::handle(ctx, args)
+    }
+
+    pub fn fill_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillSwap<'info>>,
+        args: FillSwapArgs,
+    ) -> Result<()> {
+        ins::fill_swap::handle(ctx, args)
+    }
+
+    pub fn cancel_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelSwap<'info>>,
+        args: CancelSwapArgs,
+    ) -> Result<()> {
+        ins::cancel_swap::handle(ctx, args)
+    }
+
+    pub fn transfer_listing(
+        ctx: Context<TransferListing>,
+        args: TransferListingArgs,
+    ) -> Result<()> {
+        ins::transfer_listing::handle(ctx, args)
+    }
+
+    pub fn update_fee_tiers(ctx: Context<UpdateFeeTiers>, args: UpdateFeeTiersArgs) -> Result<()> {
+        ins::update_fee_tiers::handle(ctx, args)
+    }
+
+    pub fn update_fee_recipients(
+        ctx: Context<UpdateFeeRecipients>,
+        args: UpdateFeeRecipientsArgs,
+    ) -> Result<()> {
+        ins::update_fee_recipients::handle(ctx, args)
+    }
+
+    pub fn update_fee_caps(ctx: Context<UpdateFeeCaps>, args: UpdateFeeCapsArgs) -> Result<()> {
+        ins::update_fee_caps::handle(ctx, args)
+    }
+
+    pub fn buyer_escrow_deposit(
+        ctx: Context<BuyerEscrowDeposit>,
+        args: BuyerEscrowDepositArgs,
+    ) -> Result<()> {
+        ins::buyer_escrow_deposit::handle(ctx, args)
+    }
+
+    pub fn buyer_escrow_withdraw(
+        ctx: Context<BuyerEscrowWithdraw>,
+        args: BuyerEscrowWithdrawArgs,
+    ) -> Result<()> {
+        ins::buyer_escrow_withdraw::handle(ctx, args)
+    }
+
+    pub fn buy_now_with_intent<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyNowWithIntent<'info>>,
+        args: BuyNowWithIntentArgs,
+    ) -> Result<u64> {
+        ins::buy_now_with_intent::handle(ctx, args)
+    }
+
+    pub fn close_buy_intent_receipt(ctx: Context<CloseBuyIntentReceipt>) -> Result<()> {
+        ins::close_buy_intent_receipt::handle(ctx)
+    }
+
+    pub fn set_royalty_redirect(
+        ctx: Context<SetRoyaltyRedirect>,
+        args: SetRoyaltyRedirectArgs,
+    ) -> Result<()> {
+        ins::set_royalty_redirect::handle(ctx, args)
+    }
+
+    pub fn layaway_deposit(ctx: Context<LayawayDeposit>, args: LayawayDepositArgs) -> Result<()> {
+        ins::layaway_deposit::handle(ctx, args)
+    }
+
+    pub fn layaway_default(ctx: Context<LayawayDefault>, args: LayawayDefaultArgs) -> Result<()> {
+        ins::layaway_default::handle(ctx, args)
+    }
+
+    pub fn list_rental<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListRental<'info>>,
+        args: ListRentalArgs,
+    ) -> Result<()> {
+        ins::list_rental::handle(ctx, args)
+    }
+
+    pub fn rent_asset<'info>(
+        ctx: Context<'_, '_, '_, 'info, RentAsset<'info>>,
+        args: RentAssetArgs,
+    ) -> Result<()> {
+        ins::rent_asset::handle(ctx, args)
+    }
+
+    pub fn close_rental<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseRental<'info>>,
+        args: CloseRentalArgs,
+    ) -> Result<()> {
+        ins::close_rental::handle(ctx, args)
+    }
+
+    pub fn create_loan_offer(
+        ctx: Context<CreateLoanOffer>,
+        args: CreateLoanOfferArgs,
+    ) -> Result<()> {
+        ins::create_loan_offer::handle(ctx, args)
+    }
+
+    pub fn cancel_loan_offer(ctx: Context<CancelLoanOffer>) -> Result<()> {
+        ins::cancel_loan_offer::handle(ctx)
+    }
+
+    pub fn take_loan<'info>(
+        ctx: Context<'_, '_, '_, 'info, TakeLoan<'info>>,
+        args: TakeLoanArgs,
+    ) -> Result<()> {
+        ins::take_loan::handle(ctx, args)
+    }
+
+    pub fn repay_loan<'info>(
+        ctx: Context<'_, '_, '_, 'info, RepayLoan<'info>>,
+        args: RepayLoanArgs,
+    ) -> Result<()> {
+        ins::repay_loan::handle(ctx, args)
+    }
+
+    pub fn foreclose_loan<'info>(
+        ctx: Context<'_, '_, '_, 'info, ForecloseLoan<'info>>,
+        args: ForecloseLoanArgs,
+    ) -> Result<()> {
+        ins::foreclose_loan::handle(ctx, args)
+    }
+
+    pub fn create_launchpad(
+        ctx: Context<CreateLaunchpad>,
+        args: CreateLaunchpadArgs,
+    ) -> Result<()> {
+        ins::create_launchpad::handle(ctx, args)
+    }
+
+    pub fn mint_buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintBuy<'info>>,
+        args: MintBuyArgs,
+    ) -> Result<()> {
+        ins::mint_buy::handle(ctx, args)
+    }
+
+    pub fn create_trait_bid(ctx: Context<CreateTraitBid>, args: CreateTraitBidArgs) -> Result<()> {
+        ins::create_trait_bid::handle(ctx, args)
+    }
+
+    pub fn cancel_trait_bid(ctx: Context<CancelTraitBid>) -> Result<()> {
+        ins::cancel_trait_bid::handle(ctx)
+    }
+
+    pub fn fill_trait_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillTraitBid<'info>>,
+        args: FillTraitBidArgs,
+    ) -> Result<()> {
+        ins::fill_trait_bid::handle(ctx, args)
+    }
+
+    pub fn set_collection_override(
+        ctx: Context<SetCollectionOverride>,
+        args: SetCollectionOverrideArgs,
+    ) -> Result<()> {
+        ins::set_collection_override::handle(ctx, args)
+    }
 }
@@ -1,6 +1,12 @@
 use std::ops::Deref;
 
-use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_lang::{error::ErrorCode, prelude::*, AnchorDeserialize};
+
+use crate::constants::{
+    DEFAULT_MAKER_FEE_BP, DEFAULT_SELLER_CANCEL_COOLDOWN_SECONDS, DEFAULT_TAKER_FEE_BP,
+    MAX_BUNDLE_SIZE, MAX_FEE_CAPS, MAX_FEE_RECIPIENTS, MAX_FEE_TIERS, MAX_LAUNCHPAD_PHASES,
+    MAX_METADATA_TEMPLATE_LEN, MAX_SWAP_ITEMS, VOLUME_WINDOW_DAYS,
+};
 use mpl_bubblegum::accounts::TreeConfig;
 
 #[account]
@@ -30,6 +36,32 @@ pub struct SellerTradeState {
     pub created_at: i64,
     // 189
     pub updated_at: i64,
+    // 197
+    pub seller_nonce: u64, // Seller's UserState nonce when listed, stale once the seller bumps it.
+    // 205
+    pub cancel_requested_at: i64, // Set when the seller requests a cancel without the notary, 0 otherwise.
+    // 213
+    pub payout_address: Pubkey, // Receives the sale proceeds, default means the seller.
+    // 245
+    pub rent_payer: Pubkey, // Paid the rent of this account and gets it back, default means the seller.
+    // 277
+    pub price_version: u64, // Bumped on every reprice, buyers can pin it.
+    // 285
+    pub pending_buyer_price: u64, // Price increase that takes effect at pending_price_effective_at.
+    // 293
+    pub pending_price_effective_at: i64,
+    // 301
+    pub price_increase_delay_seconds: i64, // Delay before a price increase takes effect.
+    // 309
+    pub layaway_enabled: bool,
+    // 310
+    pub layaway_penalty_bp: u16, // Share of a defaulted layaway deposit the seller keeps.
+    // 312
+    pub layaway_buyer: Pubkey, // Buyer holding the layaway lock, default when unlocked.
+    // 344
+    pub layaway_deposit: u64, // Held by this account on top of its rent.
+    // 352
+    pub layaway_deadline: i64,
 }
 
 impl SellerTradeState {
@@ -44,7 +76,996 @@ impl SellerTradeState {
       4 + // index
       8 + // created_at
       8 + // updated_at
-      240; // padding
+      8 + // seller_nonce
+      8 + // cancel_requested_at
+      32 + // payout_address
+      32 + // rent_payer
+      8 + // price_version
+      8 + // pending_buyer_price
+      8 + // pending_price_effective_at
+      8 + // price_increase_delay_seconds
+      1 + // layaway_enabled
+      2 + // layaway_penalty_bp
+      32 + // layaway_buyer
+      8 + // layaway_deposit
+      8 + // layaway_deadline
+      77; // padding
+
+    pub fn payout_address(&self) -> Pubkey {
+        if self.payout_address == Pubkey::default() {
+            self.seller
+        } else {
+            self.payout_address
+        }
+    }
+
+    pub fn rent_payer(&self) -> Pubkey {
+        if self.rent_payer == Pubkey::default() {
+            self.seller
+        } else {
+            self.rent_payer
+        }
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.layaway_buyer != Pubkey::default()
+    }
+
+    pub fn clear_layaway(&mut self) {
+        self.layaway_buyer = Pubkey::default();
+        self.layaway_deposit = 0;
+        self.layaway_deadline = 0;
+    }
+
+    // Applies a pending price increase once its delay has elapsed.
+    pub fn apply_pending_price(&mut self, now: i64) {
+        if self.pending_price_effective_at != 0 && now >= self.pending_price_effective_at {
+            self.buyer_price = self.pending_buyer_price;
+            self.pending_buyer_price = 0;
+            self.pending_price_effective_at = 0;
+        }
+    }
+}
+
+// Per-wallet state. Bumping the nonce invalidates every listing made with an older nonce.
+#[account]
+#[derive(Default, Copy)]
+pub struct UserState {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub wallet: Pubkey,
+    // 40
+    pub nonce: u64,
+    // 48
+    pub bump: u8,
+}
+
+impl UserState {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // wallet
+      8 + // nonce
+      1 + // bump
+      64; // padding
+
+    // Current nonce of a wallet, a wallet that never bumped its nonce has no UserState yet.
+    pub fn current_nonce(user_state: &AccountInfo) -> Result<u64> {
+        if user_state.data_is_empty() {
+            return Ok(0);
+        }
+        if user_state.owner != &crate::ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = user_state.try_borrow_data()?;
+        Ok(UserState::try_deserialize(&mut &data[..])?.nonce)
+    }
+}
+
+// Marks an off-chain signed order as filled so it can't be replayed.
+#[account]
+#[derive(Default, Copy)]
+pub struct SignedOrderReceipt {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub seller: Pubkey,
+    // 40
+    pub asset_id: Pubkey,
+    // 72
+    pub buyer_price: u64,
+    // 80
+    pub nonce: u64,
+    // 88
+    pub bump: u8,
+    // 89
+    pub filled_at: i64,
+    // 97
+    pub buyer: Pubkey,
+    // 129
+    pub expiry: i64,
+}
+
+impl SignedOrderReceipt {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // seller
+      32 + // asset_id
+      8 + // buyer_price
+      8 + // nonce
+      1 + // bump
+      8 + // filled_at
+      32 + // buyer
+      8 + // expiry
+      24; // padding
+}
+
+// Marks a notary signed fee override as used so it can't be replayed on a relist.
+#[account]
+#[derive(Default, Copy)]
+pub struct FeeOverrideReceipt {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub nonce: u64,
+    // 16
+    pub bump: u8,
+    // 17
+    pub buyer: Pubkey,
+    // 49
+    pub asset_id: Pubkey,
+    // 81
+    pub rent_payer: Pubkey, // Paid the rent of this account and gets it back.
+    // 113
+    pub expiry: i64,
+}
+
+impl FeeOverrideReceipt {
+    pub const LEN: usize = 8 + // discriminator
+      8 + // nonce
+      1 + // bump
+      32 + // buyer
+      32 + // asset_id
+      32 + // rent_payer
+      8 + // expiry
+      32; // padding
+}
+
+// Marks a buyer's signed intent as used so a relayer can't replay it.
+#[account]
+#[derive(Default, Copy)]
+pub struct BuyIntentReceipt {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub buyer: Pubkey,
+    // 40
+    pub asset_id: Pubkey,
+    // 72
+    pub buyer_price: u64,
+    // 80
+    pub nonce: u64,
+    // 88
+    pub bump: u8,
+    // 89
+    pub filled_at: i64,
+    // 97
+    pub relayer: Pubkey, // Paid the rent of this account and gets it back.
+    // 129
+    pub expiry: i64,
+}
+
+impl BuyIntentReceipt {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // buyer
+      32 + // asset_id
+      8 + // buyer_price
+      8 + // nonce
+      1 + // bump
+      8 + // filled_at
+      32 + // relayer
+      8 + // expiry
+      24; // padding
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct BundleItem {
+    pub merkle_tree: Pubkey,
+    pub index: u32,
+    pub asset_id: Pubkey,
+}
+
+impl BundleItem {
+    pub const LEN: usize = 32 + // merkle_tree
+      4 + // index
+      32; // asset_id
+}
+
+// Several escrowed cNFTs listed together for a single price.
+#[account]
+#[derive(Default)]
+pub struct BundleTradeState {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub seller: Pubkey,
+    // 40
+    pub seller_referral: Pubkey,
+    // 72
+    pub buyer_price: u64,
+    // 80
+    pub payment_mint: Pubkey,
+    // 112
+    pub bump: u8,
+    // 113
+    pub bundle_id: Pubkey, // Random key so a seller can have several bundles.
+    // 145
+    pub seller_nonce: u64,
+    // 153
+    pub created_at: i64,
+    // 161
+    pub updated_at: i64,
+    // 169
+    pub items: Vec<BundleItem>,
+}
+
+impl BundleTradeState {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // seller
+      32 + // seller_referral
+      8 + // buyer_price
+      32 + // payment_mint
+      1 + // bump
+      32 + // bundle_id
+      8 + // seller_nonce
+      8 + // created_at
+      8 + // updated_at
+      4 + MAX_BUNDLE_SIZE * BundleItem::LEN + // items
+      128; // padding
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum SwapWantKind {
+    // key is the asset_id of the wanted cNFT.
+    #[default]
+    AssetId,
+    // key is a verified collection, any cNFT from it fills the want.
+    Collection,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct SwapWant {
+    pub kind: SwapWantKind,
+    pub key: Pubkey,
+}
+
+impl SwapWant {
+    pub const LEN: usize = 1 + // kind
+      32; // key
+}
+
+// Barter offer: the maker escrows cNFTs and optional lamports in exchange for the wanted cNFTs.
+// The escrowed lamports are held by the swap_order account itself.
+#[account]
+#[derive(Default)]
+pub struct SwapOrder {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub maker: Pubkey,
+    // 40
+    pub swap_id: Pubkey, // Random key so a maker can have several swaps.
+    // 72
+    pub bump: u8,
+    // 73
+    pub maker_nonce: u64,
+    // 81
+    pub lamports: u64, // Escrowed lamports the taker gets on top of the offered cNFTs.
+    // 89
+    pub created_at: i64,
+    // 97
+    pub offered: Vec<BundleItem>,
+    // 101 + 68 * offered.len()
+    pub wanted: Vec<SwapWant>,
+}
+
+impl SwapOrder {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // maker
+      32 + // swap_id
+      1 + // bump
+      8 + // maker_nonce
+      8 + // lamports
+      8 + // created_at
+      4 + MAX_SWAP_ITEMS * BundleItem::LEN + // offered
+      4 + MAX_SWAP_ITEMS * SwapWant::LEN + // wanted
+      64; // padding
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum CurveType {
+    // delta is an absolute amount of lamports.
+    #[default]
+    Linear,
+    // delta is in basis points of the spot price.
+    Exponential,
+}
+
+// Two-sided liquidity pool for a single collection. The pool account holds the lamports used to
+// buy cNFTs, the cNFTs themselves are held by program_as_signer and tracked by PoolAsset accounts.
+#[account]
+#[derive(Default, Copy)]
+pub struct Pool {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub owner: Pubkey,
+    // 40
+    pub uuid: Pubkey, // Lets an owner create several pools.
+    // 72
+    pub collection: Pubkey, // Verified collection of the cNFTs the pool trades.
+    // 104
+    pub curve_type: CurveType,
+    // 105
+    pub spot_price: u64,
+    // 113
+    pub delta: u64,
+    // 121
+    pub lp_fee_bp: u16,
+    // 123
+    pub nft_count: u32,
+    // 127
+    pub bump: u8,
+    // 128
+    pub created_at: i64,
+    // 136
+    pub updated_at: i64,
+}
+
+impl Pool {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // owner
+      32 + // uuid
+      32 + // collection
+      1 + // curve_type
+      8 + // spot_price
+      8 + // delta
+      2 + // lp_fee_bp
+      4 + // nft_count
+      1 + // bump
+      8 + // created_at
+      8 + // updated_at
+      128; // padding
+}
+
+// Ties an escrowed cNFT to the pool it was deposited or sold into.
+#[account]
+#[derive(Default, Copy)]
+pub struct PoolAsset {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub pool: Pubkey,
+    // 40
+    pub asset_id: Pubkey,
+    // 72
+    pub merkle_tree: Pubkey,
+    // 104
+    pub index: u32,
+    // 108
+    pub rent_payer: Pubkey,
+    // 140
+    pub bump: u8,
+}
+
+impl PoolAsset {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // pool
+      32 + // asset_id
+      32 + // merkle_tree
+      4 + // index
+      32 + // rent_payer
+      1 + // bump
+      32; // padding
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeTier {
+    // Rolling 30 day volume in lamports a wallet needs for this tier.
+    pub min_volume: u64,
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+}
+
+impl FeeTier {
+    pub const LEN: usize = 8 + // min_volume
+      2 + // maker_fee_bp
+      2; // taker_fee_bp
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeRecipient {
+    pub address: Pubkey,
+    // Share of the platform fee, all shares add up to 10,000 bp.
+    pub share_bp: u16,
+}
+
+impl FeeRecipient {
+    pub const LEN: usize = 32 + // address
+      2; // share_bp
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeCap {
+    pub payment_mint: Pubkey,
+    // Absolute bounds of each fee in the payment_mint, applied after the bp computation.
+    pub min_fee: u64,
+    pub max_fee: u64,
+}
+
+impl FeeCap {
+    pub const LEN: usize = 32 + // payment_mint
+      8 + // min_fee
+      8; // max_fee
+}
+
+// Global program configuration, managed by the config authority.
+#[account]
+#[derive(Default)]
+pub struct Config {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub authority: Pubkey,
+    // 40
+    pub bump: u8,
+    // 41
+    pub fee_tiers: Vec<FeeTier>, // Sorted by min_volume, used when the notary doesn't sign.
+    // 45 + 12 * fee_tiers.len()
+    pub fee_recipients: Vec<FeeRecipient>, // Platform fee split, empty sends everything to ME_TREASURY.
+    // 49 + 12 * fee_tiers.len() + 34 * fee_recipients.len()
+    pub fee_caps: Vec<FeeCap>, // At most one per payment_mint.
+    // 53 + 12 * fee_tiers.len() + 34 * fee_recipients.len() + 48 * fee_caps.len()
+    pub seller_cancel_cooldown_seconds: i64,
+}
+
+impl Config {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // authority
+      1 + // bump
+      4 + MAX_FEE_TIERS * FeeTier::LEN + // fee_tiers
+      4 + MAX_FEE_RECIPIENTS * FeeRecipient::LEN + // fee_recipients
+      4 + MAX_FEE_CAPS * FeeCap::LEN + // fee_caps
+      8 + // seller_cancel_cooldown_seconds
+      168; // padding
+
+    // Config at the given address, the defaults (no tiers, recipients or caps) until init_config ran.
+    pub fn load(config: &AccountInfo) -> Result<Self> {
+        if config.data_is_empty() {
+            return Ok(Self {
+                seller_cancel_cooldown_seconds: DEFAULT_SELLER_CANCEL_COOLDOWN_SECONDS,
+                ..Self::default()
+            });
+        }
+        if config.owner != &crate::ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = config.try_borrow_data()?;
+        Config::try_deserialize(&mut &data[..])
+    }
+
+    pub fn fee_cap(&self, payment_mint: &Pubkey) -> Option<FeeCap> {
+        self.fee_caps
+            .iter()
+            .find(|cap| cap.payment_mint == *payment_mint)
+            .copied()
+    }
+
+    // (maker, taker) fee bp for the given rolling volumes of the seller and buyer.
+    pub fn tier_fee_bp(&self, maker_volume: u64, taker_volume: u64) -> (i16, u16) {
+        let tier_for = |volume: u64| {
+            self.fee_tiers
+                .iter()
+                .rev()
+                .find(|tier| volume >= tier.min_volume)
+        };
+        (
+            tier_for(maker_volume).map_or(DEFAULT_MAKER_FEE_BP, |tier| tier.maker_fee_bp),
+            tier_for(taker_volume).map_or(DEFAULT_TAKER_FEE_BP, |tier| tier.taker_fee_bp),
+        )
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum OverrideKind {
+    // key is a verified collection mint.
+    #[default]
+    Collection,
+    // key is the asset's creator hash.
+    CreatorHash,
+}
+
+// Fee and royalty terms agreed with a collection, used instead of the fee tiers when the
+// notary doesn't sign.
+#[account]
+#[derive(Default, Copy)]
+pub struct CollectionOverride {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub key: Pubkey,
+    // 40
+    pub kind: OverrideKind,
+    // 41
+    pub bump: u8,
+    // 42
+    pub maker_fee_bp: i16,
+    // 44
+    pub taker_fee_bp: u16,
+    // 46
+    pub creator_royalty_bp: u16, // Share of the royalties enforced, replaces DEFAULT_CREATOR_ROYALTY_BP.
+    // 48
+    pub updated_at: i64,
+}
+
+impl CollectionOverride {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // key
+      1 + // kind
+      1 + // bump
+      2 + // maker_fee_bp
+      2 + // taker_fee_bp
+      2 + // creator_royalty_bp
+      8 + // updated_at
+      64; // padding
+
+    // Override at the given address, None if it was never created.
+    pub fn load(collection_override: &AccountInfo) -> Result<Option<Self>> {
+        if collection_override.data_is_empty() {
+            return Ok(None);
+        }
+        if collection_override.owner != &crate::ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = collection_override.try_borrow_data()?;
+        Ok(Some(CollectionOverride::try_deserialize(&mut &data[..])?))
+    }
+}
+
+// Prepaid balance a buyer's purchases and offers draw from. The balance is held by the account
+// itself, on top of its rent.
+#[account]
+#[derive(Default, Copy)]
+pub struct BuyerEscrow {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub buyer: Pubkey,
+    // 40
+    pub bump: u8,
+    // 41
+    pub created_at: i64,
+}
+
+impl BuyerEscrow {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // buyer
+      1 + // bump
+      8 + // created_at
+      64; // padding
+}
+
+// Where a creator wants their royalties paid instead of their own address, set by the creator.
+#[account]
+#[derive(Default, Copy)]
+pub struct RoyaltyRedirect {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub creator: Pubkey,
+    // 40
+    pub destination: Pubkey,
+    // 72
+    pub bump: u8,
+    // 73
+    pub updated_at: i64,
+}
+
+impl RoyaltyRedirect {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // creator
+      32 + // destination
+      1 + // bump
+      8 + // updated_at
+      32; // padding
+}
+
+// An escrowed cNFT rented out per period. The listing itself owns the asset, so the asset's
+// on-chain owner leads to the current renter: it's rented to `renter` while is_rented. The renter
+// isn't made leaf delegate, since a delegate could transfer the asset out of escrow.
+#[account]
+#[derive(Default, Copy)]
+pub struct RentalListing {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub owner: Pubkey,
+    // 40
+    pub asset_id: Pubkey,
+    // 72
+    pub merkle_tree: Pubkey,
+    // 104
+    pub index: u32,
+    // 108
+    pub nonce: u64, // Asset's leaf nonce.
+    // 116
+    pub data_hash: [u8; 32], // Verified by Bubblegum when the asset was escrowed.
+    // 148
+    pub creator_hash: [u8; 32],
+    // 180
+    pub seller_fee_basis_points: u16,
+    // 182
+    pub bump: u8,
+    // 183
+    pub price_per_period: u64,
+    // 191
+    pub period_seconds: i64,
+    // 199
+    pub max_periods: u32,
+    // 203
+    pub renter: Pubkey, // Default when the asset was never rented.
+    // 235
+    pub rental_end: i64,
+    // 243
+    pub created_at: i64,
+    // 251
+    pub collection: Pubkey, // Asset's verified collection, default when it has none.
+}
+
+impl RentalListing {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // owner
+      32 + // asset_id
+      32 + // merkle_tree
+      4 + // index
+      8 + // nonce
+      32 + // data_hash
+      32 + // creator_hash
+      2 + // seller_fee_basis_points
+      1 + // bump
+      8 + // price_per_period
+      8 + // period_seconds
+      4 + // max_periods
+      32 + // renter
+      8 + // rental_end
+      8 + // created_at
+      32 + // collection
+      32; // padding
+
+    pub fn is_rented(&self, now: i64) -> bool {
+        self.renter != Pubkey::default() && now < self.rental_end
+    }
+}
+
+// A lender's SOL offer against any cNFT of a verified collection. Nothing is locked up front, the
+// principal is drawn from the lender's BuyerEscrow when a borrower takes the offer.
+#[account]
+#[derive(Default, Copy)]
+pub struct LoanOffer {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub lender: Pubkey,
+    // 40
+    pub offer_id: Pubkey, // Random key so a lender can have several offers.
+    // 72
+    pub bump: u8,
+    // 73
+    pub lender_nonce: u64,
+    // 81
+    pub collection: Pubkey,
+    // 113
+    pub principal: u64,
+    // 121
+    pub interest_bp: u16, // Flat interest on the principal for the whole duration.
+    // 123
+    pub duration_seconds: i64,
+    // 131
+    pub created_at: i64,
+}
+
+impl LoanOffer {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // lender
+      32 + // offer_id
+      1 + // bump
+      8 + // lender_nonce
+      32 + // collection
+      8 + // principal
+      2 + // interest_bp
+      8 + // duration_seconds
+      8 + // created_at
+      64; // padding
+}
+
+// An active loan, the collateral is escrowed with program_as_signer until it's repaid or
+// foreclosed.
+#[account]
+#[derive(Default, Copy)]
+pub struct Loan {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub lender: Pubkey,
+    // 40
+    pub borrower: Pubkey,
+    // 72
+    pub asset_id: Pubkey,
+    // 104
+    pub merkle_tree: Pubkey,
+    // 136
+    pub index: u32,
+    // 140
+    pub nonce: u64, // Asset's leaf nonce.
+    // 148
+    pub data_hash: [u8; 32], // Verified by Bubblegum when the collateral was escrowed.
+    // 180
+    pub creator_hash: [u8; 32],
+    // 212
+    pub bump: u8,
+    // 213
+    pub principal: u64,
+    // 221
+    pub repay_amount: u64, // Principal plus interest.
+    // 229
+    pub due_at: i64,
+    // 237
+    pub created_at: i64,
+}
+
+impl Loan {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // lender
+      32 + // borrower
+      32 + // asset_id
+      32 + // merkle_tree
+      4 + // index
+      8 + // nonce
+      32 + // data_hash
+      32 + // creator_hash
+      1 + // bump
+      8 + // principal
+      8 + // repay_amount
+      8 + // due_at
+      8 + // created_at
+      64; // padding
+}
+
+// Primary sale of a compressed collection. Assets are minted on purchase into the creator's tree,
+// which has program_as_signer as tree delegate and collection authority.
+#[account]
+#[derive(Default)]
+pub struct Launchpad {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub creator: Pubkey,
+    // 40
+    pub launchpad_id: Pubkey, // Random key so a creator can have several launchpads.
+    // 72
+    pub bump: u8,
+    // 73
+    pub merkle_tree: Pubkey,
+    // 105
+    pub collection_mint: Pubkey,
+    // 137
+    pub price: u64,
+    // 145
+    pub supply: u32,
+    // 149
+    pub minted: u32,
+    // 153
+    pub start_at: i64,
+    // 161
+    pub metadata_template: Vec<u8>, // Borsh serialized MetadataArgs, numbered on mint.
+    // 165 + metadata_template.len()
+    pub phases: Vec<LaunchpadPhase>, // When set, mints go through a phase and price/start_at are unused.
+}
+
+impl Launchpad {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // creator
+      32 + // launchpad_id
+      1 + // bump
+      32 + // merkle_tree
+      32 + // collection_mint
+      8 + // price
+      4 + // supply
+      4 + // minted
+      8 + // start_at
+      4 + MAX_METADATA_TEMPLATE_LEN + // metadata_template
+      4 + MAX_LAUNCHPAD_PHASES * LaunchpadPhase::LEN + // phases
+      128; // padding
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct LaunchpadPhase {
+    pub price: u64,
+    pub wallet_cap: u32, // Mints per wallet in this phase, 0 for no cap.
+    pub start_at: i64,
+    pub end_at: i64,
+    // Keccak merkle root of the allowlisted wallets, None for a public phase.
+    pub allowlist_root: Option<[u8; 32]>,
+}
+
+impl LaunchpadPhase {
+    pub const LEN: usize = 8 + // price
+      4 + // wallet_cap
+      8 + // start_at
+      8 + // end_at
+      1 + 32; // allowlist_root
+}
+
+// Mints of a wallet per launchpad phase, to enforce the phases' wallet_cap.
+#[account]
+#[derive(Default, Copy)]
+pub struct LaunchpadMintCount {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub launchpad: Pubkey,
+    // 40
+    pub wallet: Pubkey,
+    // 72
+    pub bump: u8,
+    // 73
+    pub minted: [u32; MAX_LAUNCHPAD_PHASES], // Indexed like launchpad.phases.
+}
+
+impl LaunchpadMintCount {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // launchpad
+      32 + // wallet
+      1 + // bump
+      4 * MAX_LAUNCHPAD_PHASES + // minted
+      32; // padding
+}
+
+// Standing bid on any asset of a collection whose asset_id is in trait_root, e.g. every asset with
+// a given trait. Fills are paid from the buyer's BuyerEscrow.
+#[account]
+#[derive(Default, Copy)]
+pub struct TraitBid {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub buyer: Pubkey,
+    // 40
+    pub bid_id: Pubkey, // Random key so a buyer can have several bids.
+    // 72
+    pub bump: u8,
+    // 73
+    pub buyer_nonce: u64,
+    // 81
+    pub collection: Pubkey,
+    // 113
+    pub trait_root: [u8; 32], // Keccak merkle root of the eligible asset_ids, by the buyer or a curator.
+    // 145
+    pub price: u64,
+    // 153
+    pub quantity: u32,
+    // 157
+    pub filled: u32,
+    // 161
+    pub expiry: i64, // 0 for no expiry.
+    // 169
+    pub created_at: i64,
+}
+
+impl TraitBid {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // buyer
+      32 + // bid_id
+      1 + // bump
+      8 + // buyer_nonce
+      32 + // collection
+      32 + // trait_root
+      8 + // price
+      4 + // quantity
+      4 + // filled
+      8 + // expiry
+      8 + // created_at
+      64; // padding
+}
+
+// Per-wallet trading volume, kept in daily buckets for a rolling window.
+#[account]
+#[derive(Default, Copy)]
+pub struct TraderStats {
+    // Byte offsets:
+    // 0
+    // Discriminator
+
+    // 8
+    pub wallet: Pubkey,
+    // 40
+    pub bump: u8,
+    // 41
+    pub last_day: i64, // Unix day of the latest recorded trade.
+    // 49
+    pub daily_volume: [u64; VOLUME_WINDOW_DAYS],
+}
+
+impl TraderStats {
+    pub const LEN: usize = 8 + // discriminator
+      32 + // wallet
+      1 + // bump
+      8 + // last_day
+      8 * VOLUME_WINDOW_DAYS + // daily_volume
+      64; // padding
+
+    // Drops the buckets that fell out of the window, must be called before reading or recording.
+    pub fn roll(&mut self, now: i64) {
+        let day = now / (60 * 60 * 24);
+        let elapsed = (day - self.last_day).clamp(0, VOLUME_WINDOW_DAYS as i64);
+        for d in 1..=elapsed {
+            self.daily_volume[((self.last_day + d) % VOLUME_WINDOW_DAYS as i64) as usize] = 0;
+        }
+        self.last_day = self.last_day.max(day);
+    }
+
+    pub fn rolling_volume(&self) -> u64 {
+        self.daily_volume
+            .iter()
+            .fold(0u64, |total, volume| total.saturating_add(*volume))
+    }
+
+    pub fn record(&mut self, volume: u64) {
+        let bucket = (self.last_day % VOLUME_WINDOW_DAYS as i64) as usize;
+        self.daily_volume[bucket] = self.daily_volume[bucket].saturating_add(volume);
+    }
+
+    // Rolls and records in one go, rolling again after reading the fee tiers is a no-op.
+    pub fn record_fill(&mut self, wallet: Pubkey, bump: u8, now: i64, volume: u64) {
+        self.wallet = wallet;
+        self.bump = bump;
+        self.roll(now);
+        self.record(volume);
+    }
 }
 
 // Wrapper structs to replace the Anchor program types until the Metaplex libs have
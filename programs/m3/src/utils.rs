@@ -1,16 +1,32 @@
-use mpl_bubblegum::{hash::hash_creators, types::Creator};
+use mpl_bubblegum::{
+    hash::{hash_creators, hash_metadata},
+    types::{Creator, MetadataArgs},
+};
 use solana_program::keccak;
 use spl_account_compression::{program::SplAccountCompression, Noop};
 
 use crate::{
-    constants::{DEFAULT_MAKER_FEE_BP, DEFAULT_TAKER_FEE_BP, MAX_FEE_ABS_BP},
+    constants::{
+        COLLECTION_OVERRIDE, DEFAULT_CREATOR_ROYALTY_BP, MAX_FEE_ABS_BP, MAX_LP_FEE_BP, MAX_PRICE,
+        PREFIX, ROYALTY_REDIRECT,
+    },
     errors::ErrorCode,
+    states::{
+        BuyerEscrow, CollectionOverride, Config, CurveType, FeeCap, FeeRecipient, OverrideKind,
+        RoyaltyRedirect,
+    },
 };
 
 use {
     anchor_lang::{
         prelude::*,
-        solana_program::{program::invoke, program::invoke_signed, system_instruction},
+        solana_program::{
+            ed25519_program,
+            program::invoke,
+            program::invoke_signed,
+            system_instruction,
+            sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+        },
     },
     arrayref::array_ref,
     std::{convert::TryFrom, convert::TryInto, slice::Iter},
@@ -25,6 +41,20 @@ pub fn get_mint_from_token_account(token_account_info: &AccountInfo) -> Result<P
     Ok(Pubkey::new_from_array(*mint_data))
 }
 
+/// Splits the next `len` accounts off the front of `accounts`, for instructions that pass
+/// several assets' accounts through remaining_accounts.
+pub fn take_accounts<'a, 'info>(
+    accounts: &mut &'a [AccountInfo<'info>],
+    len: usize,
+) -> Result<&'a [AccountInfo<'info>]> {
+    if accounts.len() < len {
+        return Err(ErrorCode::InvalidRemainingAccounts.into());
+    }
+    let (taken, rest) = accounts.split_at(len);
+    *accounts = rest;
+    Ok(taken)
+}
+
 /// Create account almost from scratch, lifted from
 /// https://github.com/solana-labs/solana-program-library/blob/7d4873c61721aca25464d42cc5ef651a7923ca79/associated-token-account/program/src/processor.rs#L51-L98
 #[inline(always)]
@@ -90,6 +120,10 @@ pub fn transfer_compressed_nft<'info>(
     index: u32,
     signer_seeds: Option<&[&[u8]]>,
 ) -> Result<()> {
+    // When a PDA delegate moves a leaf it doesn't own (e.g. a signed order), the delegate
+    // signs instead of the owner.
+    let delegate_signs = signer_seeds.is_some() && leaf_delegate.key() != leaf_owner.key();
+
     // proof_path are the accounts that make up the required proof
     let proof_path_len = proof_path.len();
     let mut accounts = Vec::with_capacity(
@@ -98,8 +132,8 @@ pub fn transfer_compressed_nft<'info>(
     );
     accounts.extend(vec![
         AccountMeta::new_readonly(tree_authority.key(), false),
-        AccountMeta::new_readonly(leaf_owner.key(), true),
-        AccountMeta::new_readonly(leaf_delegate.key(), false),
+        AccountMeta::new_readonly(leaf_owner.key(), !delegate_signs),
+        AccountMeta::new_readonly(leaf_delegate.key(), delegate_signs),
         AccountMeta::new_readonly(new_leaf_owner.key(), false),
         AccountMeta::new(merkle_tree.key(), false),
         AccountMeta::new_readonly(log_wrapper.key(), false),
@@ -168,14 +202,268 @@ fn multiply_divide(value: u128, multiplier: u128, divisor: u128) -> Result<u128>
         .ok_or(ErrorCode::NumericalOverflow.into())
 }
 
+// Splits a bundle price evenly between the items, the first item gets the rounding remainder.
+pub fn bundle_item_prices(item_count: usize, buyer_price: u64) -> Vec<u64> {
+    let count = item_count as u64;
+    (0..count)
+        .map(|index| {
+            if index == 0 {
+                buyer_price / count + buyer_price % count
+            } else {
+                buyer_price / count
+            }
+        })
+        .collect()
+}
+
+// Pays out of a program-owned account (e.g. a BuyerEscrow) by moving the lamports directly, and
+// with a system transfer out of a wallet.
+pub fn transfer_lamports<'a>(
+    from: &AccountInfo<'a>,
+    to: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    if from.owner != &crate::ID {
+        return invoke(
+            &system_instruction::transfer(from.key, to.key, amount),
+            &[from.clone(), to.clone(), system_program.clone()],
+        )
+        .map_err(Into::into);
+    }
+    let from_lamports = from
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ErrorCode::InsufficientEscrowBalance)?;
+    let to_lamports = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    **from.try_borrow_mut_lamports()? = from_lamports;
+    **to.try_borrow_mut_lamports()? = to_lamports;
+    Ok(())
+}
+
+// Fails if paying out of the buyer escrow dipped into its rent-exempt minimum. Returns the amount
+// spent since `lamports_before`.
+pub fn settle_buyer_escrow(buyer_escrow: &AccountInfo, lamports_before: u64) -> Result<u64> {
+    let rent_exempt = Rent::get()?.minimum_balance(BuyerEscrow::LEN);
+    let spent = lamports_before.saturating_sub(buyer_escrow.lamports());
+    if buyer_escrow.lamports() < rent_exempt {
+        msg!(
+            "Buyer escrow short: {{\"needed\":{},\"available\":{}}}",
+            spent,
+            lamports_before.saturating_sub(rent_exempt)
+        );
+        return Err(ErrorCode::InsufficientEscrowBalance.into());
+    }
+    Ok(spent)
+}
+
+// Splits the platform fee between the configured fee recipients, the last recipient gets the
+// rounding remainder. Everything goes to the treasury when no recipients are configured.
+// Returns the amount paid to each address.
+pub fn pay_platform_fees<'a>(
+    payer: &AccountInfo<'a>,
+    treasury: &AccountInfo<'a>,
+    recipient_accounts: &[AccountInfo<'a>],
+    fee_recipients: &[FeeRecipient],
+    system_program: &AccountInfo<'a>,
+    total_platform_fee: u64,
+) -> Result<Vec<(Pubkey, u64)>> {
+    if recipient_accounts.len() != fee_recipients.len()
+        || recipient_accounts
+            .iter()
+            .zip(fee_recipients)
+            .any(|(account, recipient)| account.key != &recipient.address)
+    {
+        return Err(ErrorCode::InvalidRemainingAccounts.into());
+    }
+
+    let mut payouts = Vec::with_capacity(fee_recipients.len().max(1));
+    if fee_recipients.is_empty() {
+        payouts.push((treasury, total_platform_fee));
+    } else {
+        let mut remaining = total_platform_fee;
+        for (index, (account, recipient)) in
+            recipient_accounts.iter().zip(fee_recipients).enumerate()
+        {
+            let amount = if index == fee_recipients.len() - 1 {
+                remaining
+            } else {
+                multiply_divide(
+                    total_platform_fee as u128,
+                    recipient.share_bp as u128,
+                    10000,
+                )? as u64
+            };
+            remaining = remaining
+                .checked_sub(amount)
+                .ok_or(ErrorCode::NumericalOverflow)?;
+            payouts.push((account, amount));
+        }
+    }
+
+    let mut paid = Vec::with_capacity(payouts.len());
+    for (account, amount) in payouts {
+        transfer_lamports(payer, account, system_program, amount)?;
+        paid.push((account.key(), amount));
+    }
+    Ok(paid)
+}
+
+// Accounts a listing purchase moves lamports between, shared by buy_now and buy_now_with_intent.
+pub struct PurchaseAccounts<'b, 'a> {
+    // The buyer's wallet, or their BuyerEscrow when the purchase is paid out of it.
+    pub payer: &'b AccountInfo<'a>,
+    pub buyer: &'b AccountInfo<'a>,
+    pub seller: &'b AccountInfo<'a>,
+    pub payout_address: &'b AccountInfo<'a>,
+    pub notary: &'b AccountInfo<'a>,
+    pub platform_treasury: &'b AccountInfo<'a>,
+    pub system_program: &'b AccountInfo<'a>,
+    pub creator_hash_override: &'b AccountInfo<'a>,
+    pub collection_override: &'b AccountInfo<'a>,
+}
+
+// Terms of a listing purchase, taken from the instruction args.
+pub struct PurchaseTerms<'b> {
+    pub buyer_price: u64,
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+    pub creator_shares: Vec<u16>,
+    pub creator_verified: Vec<bool>,
+    pub seller_fee_basis_points: u16,
+    // Bubblegum data_hash, including seller_fee_basis_points.
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    // Borsh serialized MetadataArgs, checked against data_hash and creator_hash.
+    pub metadata_args: &'b [u8],
+    // Notary signed fees, they take precedence over the asset's override.
+    pub fee_override_bp: Option<(i16, u16)>,
+    // The seller's and buyer's volume tiers.
+    pub tier_fee_bp: (i16, u16),
+}
+
+pub struct PurchasePayments<'c, 'a> {
+    pub fees: FeeResults,
+    pub platform_fees_paid: Vec<(Pubkey, u64)>,
+    pub proof_path: &'c [AccountInfo<'a>],
+}
+
+impl PurchasePayments<'_, '_> {
+    pub fn fee_recipients_log(&self) -> String {
+        self.platform_fees_paid
+            .iter()
+            .map(|(address, amount)| {
+                format!("{{\"address\":\"{}\",\"amount\":{}}}", address, amount)
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+// Pays the royalties, the seller and the platform fees of a listing purchase out of the payer.
+// Remaining accounts are 1. (Optional) creator addresses, 2. a (RoyaltyRedirect, payee) pair per
+// creator, 3. the config's fee recipients in order and 4. Merkle proof path, which is returned
+// for the transfer.
+pub fn pay_for_listing<'c, 'a>(
+    accounts: &PurchaseAccounts<'_, 'a>,
+    terms: PurchaseTerms,
+    mut remaining_accounts: &'c [AccountInfo<'a>],
+    config: &Config,
+    payment_mint: &Pubkey,
+) -> Result<PurchasePayments<'c, 'a>> {
+    let creator_shares_length = terms.creator_shares.len();
+    let creator_accounts = take_accounts(&mut remaining_accounts, creator_shares_length)?;
+    let royalty_redirects = take_accounts(&mut remaining_accounts, 2 * creator_shares_length)?;
+    let fee_recipient_accounts =
+        take_accounts(&mut remaining_accounts, config.fee_recipients.len())?;
+
+    // The asset's override replaces the default royalty share and the fee tiers.
+    let metadata = check_metadata_args(terms.metadata_args, terms.data_hash, terms.creator_hash)?;
+    let collection_override = load_collection_override(
+        accounts.creator_hash_override,
+        terms.creator_hash,
+        accounts.collection_override,
+        verified_collection(&metadata),
+    )?;
+
+    // 1: Pay Creator Fees
+    // Bubblegum will verify the creator_hash for us, but we should verify the input creators match.
+    verify_creators(
+        creator_accounts.iter(),
+        terms.creator_shares.clone(),
+        terms.creator_verified,
+        terms.creator_hash,
+    )?;
+    pay_creator_fees(
+        &mut creator_accounts.iter(),
+        terms.creator_shares,
+        royalty_redirects,
+        accounts.payer,
+        accounts.system_program,
+        terms.buyer_price,
+        collection_override.as_ref(),
+        terms.seller_fee_basis_points,
+    )?;
+
+    // 2: Calculate fees, falling back to the notary's signed fee override, the asset's override
+    // or else the volume tiers.
+    let fees = calculate_fees(
+        accounts.notary,
+        terms.buyer_price,
+        terms.maker_fee_bp,
+        terms.taker_fee_bp,
+        accounts.buyer,
+        accounts.seller,
+        terms.fee_override_bp.unwrap_or(terms.tier_fee_bp),
+        match terms.fee_override_bp {
+            Some(_) => None,
+            None => collection_override.as_ref(),
+        },
+        config.fee_cap(payment_mint),
+    )?;
+
+    // 3: Buyer pays Seller
+    transfer_lamports(
+        accounts.payer,
+        accounts.payout_address,
+        accounts.system_program,
+        fees.seller_will_get_from_buyer,
+    )?;
+
+    // 4. Buyer pays Treasury, split between the configured fee recipients.
+    let platform_fees_paid = pay_platform_fees(
+        accounts.payer,
+        accounts.platform_treasury,
+        fee_recipient_accounts,
+        &config.fee_recipients,
+        accounts.system_program,
+        fees.total_platform_fee,
+    )?;
+
+    Ok(PurchasePayments {
+        fees,
+        platform_fees_paid,
+        proof_path: remaining_accounts,
+    })
+}
+
+// royalty_redirects has a (RoyaltyRedirect PDA, payee) pair per creator, in creator order. The
+// payee is the redirect's destination, or the creator when the PDA doesn't exist.
 #[allow(clippy::too_many_arguments)]
 pub fn pay_creator_fees<'a>(
     creator_accounts: &mut Iter<AccountInfo<'a>>,
     creator_shares: Vec<u16>,
+    royalty_redirects: &[AccountInfo<'a>],
     escrow_payment_account: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
     total_price: u64,
-    buyer_creator_royalty_bp: u16,
+    collection_override: Option<&CollectionOverride>,
     seller_fee_basis_points: u16,
 ) -> Result<u64> {
     // If there are no creators, return early with 0 fee paid
@@ -183,7 +471,9 @@ pub fn pay_creator_fees<'a>(
         return Ok(0);
     }
     // Check if the lengths of remaining_accounts, creator_shares are the same
-    if creator_accounts.len() != creator_shares.len() {
+    if creator_accounts.len() != creator_shares.len()
+        || royalty_redirects.len() != 2 * creator_shares.len()
+    {
         msg!(
             "Mismatched creator share lengths: {{\"creators\":{},\"shares\":{}}}",
             creator_accounts.len(),
@@ -193,6 +483,8 @@ pub fn pay_creator_fees<'a>(
     }
 
     let royalty_bp = seller_fee_basis_points;
+    let buyer_creator_royalty_bp =
+        collection_override.map_or(DEFAULT_CREATOR_ROYALTY_BP, |o| o.creator_royalty_bp);
 
     // Royalty and buyerCreatorRoyalty
     let total_fee = multiply_divide(total_price as u128, royalty_bp as u128, 10000)
@@ -216,19 +508,13 @@ pub fn pay_creator_fees<'a>(
             .checked_div(100)
             .ok_or(ErrorCode::NumericalOverflow)? as u64;
 
-        if creator_fee + creator_account.lamports() >= Rent::get()?.minimum_balance(0) {
-            invoke(
-                &system_instruction::transfer(
-                    escrow_payment_account.key,
-                    &creator_account.key(),
-                    creator_fee,
-                ),
-                &[
-                    escrow_payment_account.clone(),
-                    creator_account.clone(),
-                    system_program.clone(),
-                ],
-            )?;
+        let payee = royalty_payee(
+            creator_account,
+            &royalty_redirects[2 * index],
+            &royalty_redirects[2 * index + 1],
+        )?;
+        if creator_fee + payee.lamports() >= Rent::get()?.minimum_balance(0) {
+            transfer_lamports(escrow_payment_account, payee, system_program, creator_fee)?;
 
             total_fee_paid = total_fee_paid
                 .checked_add(creator_fee)
@@ -243,6 +529,39 @@ pub fn pay_creator_fees<'a>(
     Ok(total_fee_paid)
 }
 
+// Checks that the payee passed for a creator is the destination of the creator's RoyaltyRedirect,
+// or the creator itself when it never registered one.
+fn royalty_payee<'b, 'a>(
+    creator_account: &AccountInfo<'a>,
+    redirect_account: &AccountInfo<'a>,
+    payee: &'b AccountInfo<'a>,
+) -> Result<&'b AccountInfo<'a>> {
+    let (redirect_address, _) = Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            ROYALTY_REDIRECT.as_bytes(),
+            creator_account.key.as_ref(),
+        ],
+        &crate::ID,
+    );
+    if redirect_account.key != &redirect_address {
+        return Err(ErrorCode::InvalidRemainingAccounts.into());
+    }
+    let destination = if redirect_account.data_is_empty() {
+        creator_account.key()
+    } else {
+        if redirect_account.owner != &crate::ID {
+            return Err(ErrorCode::InvalidRemainingAccounts.into());
+        }
+        let data = redirect_account.try_borrow_data()?;
+        RoyaltyRedirect::try_deserialize(&mut &data[..])?.destination
+    };
+    if payee.key != &destination {
+        return Err(ErrorCode::InvalidRemainingAccounts.into());
+    }
+    Ok(payee)
+}
+
 pub fn verify_creators(
     creator_accounts: Iter<AccountInfo>,
     creator_shares: Vec<u16>,
@@ -292,6 +611,155 @@ pub fn hash_metadata_data(
     Ok(keccak::hashv(&[&metadata_args_hash, &seller_fee_basis_points.to_le_bytes()]).to_bytes())
 }
 
+// Verifies a keccak merkle proof where each pair is hashed in sorted order, so proofs don't need
+// to carry the sibling side.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == root
+}
+
+// Decodes borsh serialized MetadataArgs, for instructions that need to inspect the asset's metadata
+// instead of trusting hashes. Also returns the data_hash and creator_hash Bubblegum expects.
+pub fn decode_metadata_args(metadata_args: &[u8]) -> Result<(MetadataArgs, [u8; 32], [u8; 32])> {
+    let metadata =
+        MetadataArgs::try_from_slice(metadata_args).map_err(|_| ErrorCode::InvalidMetadataArgs)?;
+    let data_hash = hash_metadata(&metadata).map_err(|_| ErrorCode::InvalidMetadataArgs)?;
+    let creator_hash = hash_creators(&metadata.creators);
+    Ok((metadata, data_hash, creator_hash))
+}
+
+// Decodes MetadataArgs passed along with the asset's hashes, which Bubblegum verifies on transfer.
+pub fn check_metadata_args(
+    metadata_args: &[u8],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+) -> Result<MetadataArgs> {
+    let (metadata, metadata_data_hash, metadata_creator_hash) =
+        decode_metadata_args(metadata_args)?;
+    if metadata_data_hash != data_hash || metadata_creator_hash != creator_hash {
+        return Err(ErrorCode::InvalidMetadataArgs.into());
+    }
+    Ok(metadata)
+}
+
+pub fn assert_valid_pool_params(
+    curve_type: CurveType,
+    spot_price: u64,
+    delta: u64,
+    lp_fee_bp: u16,
+) -> Result<()> {
+    if spot_price > MAX_PRICE || spot_price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if lp_fee_bp > MAX_LP_FEE_BP || (curve_type == CurveType::Exponential && delta > 10000) {
+        return Err(ErrorCode::InvalidPoolParams.into());
+    }
+    Ok(())
+}
+
+// Finds the override agreed for an asset, one keyed by its creator hash takes precedence over one
+// keyed by its verified collection. The override accounts are checked against their derived
+// addresses and have to be passed even when they were never created, so a trader can't leave an
+// existing override out. collection is the asset's verified collection, if it has one.
+pub fn load_collection_override(
+    creator_hash_override: &AccountInfo,
+    creator_hash: [u8; 32],
+    collection_override: &AccountInfo,
+    collection: Option<&Pubkey>,
+) -> Result<Option<CollectionOverride>> {
+    if let Some(o) = override_at(
+        creator_hash_override,
+        &creator_hash,
+        OverrideKind::CreatorHash,
+    )? {
+        return Ok(Some(o));
+    }
+    match collection {
+        Some(collection) => override_at(
+            collection_override,
+            collection.as_ref(),
+            OverrideKind::Collection,
+        ),
+        None => Ok(None),
+    }
+}
+
+// The asset's collection, when it's verified.
+pub fn verified_collection(metadata: &MetadataArgs) -> Option<&Pubkey> {
+    metadata
+        .collection
+        .as_ref()
+        .filter(|collection| collection.verified)
+        .map(|collection| &collection.key)
+}
+
+fn override_at(
+    collection_override: &AccountInfo,
+    key: &[u8],
+    kind: OverrideKind,
+) -> Result<Option<CollectionOverride>> {
+    let (address, _) = Pubkey::find_program_address(
+        &[PREFIX.as_bytes(), COLLECTION_OVERRIDE.as_bytes(), key],
+        &crate::ID,
+    );
+    if collection_override.key != &address {
+        return Err(ErrorCode::InvalidCollectionOverride.into());
+    }
+    match CollectionOverride::load(collection_override)? {
+        Some(o) if o.kind != kind => Err(ErrorCode::InvalidCollectionOverride.into()),
+        o => Ok(o),
+    }
+}
+
+// Checks that the asset belongs to the verified collection.
+pub fn assert_verified_collection(metadata: &MetadataArgs, collection: &Pubkey) -> Result<()> {
+    match &metadata.collection {
+        Some(c) if c.verified && c.key == *collection => Ok(()),
+        _ => {
+            msg!("Asset is not part of collection: {}", collection);
+            Err(ErrorCode::InvalidCollection.into())
+        }
+    }
+}
+
+// Spot price of a pool after it sold a cNFT.
+pub fn curve_price_up(curve_type: CurveType, spot_price: u64, delta: u64) -> Result<u64> {
+    match curve_type {
+        CurveType::Linear => spot_price.checked_add(delta),
+        CurveType::Exponential => u64::try_from(multiply_divide(
+            spot_price as u128,
+            10000 + delta as u128,
+            10000,
+        )?)
+        .ok(),
+    }
+    .ok_or(ErrorCode::NumericalOverflow.into())
+}
+
+// Spot price of a pool after it bought a cNFT, the inverse of curve_price_up.
+pub fn curve_price_down(curve_type: CurveType, spot_price: u64, delta: u64) -> Result<u64> {
+    match curve_type {
+        CurveType::Linear => spot_price.checked_sub(delta),
+        CurveType::Exponential => u64::try_from(multiply_divide(
+            spot_price as u128,
+            10000,
+            10000 + delta as u128,
+        )?)
+        .ok(),
+    }
+    .ok_or(ErrorCode::InvalidPrice.into())
+}
+
+pub fn get_lp_fee(price: u64, lp_fee_bp: u16) -> Result<u64> {
+    Ok(multiply_divide(price as u128, lp_fee_bp as u128, 10000)? as u64)
+}
+
 pub struct FeeResults {
     pub maker_fee: i64,
     pub taker_fee: u64,
@@ -299,6 +767,10 @@ pub struct FeeResults {
     pub total_platform_fee: u64,
 }
 
+// fallback_fee_bp is the (maker, taker) bp used when the notary doesn't sign, usually
+// (DEFAULT_MAKER_FEE_BP, DEFAULT_TAKER_FEE_BP) or the traders' fee tiers. A collection_override
+// takes its place. fee_cap bounds the resulting fees for the payment mint.
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_fees(
     notary: &AccountInfo,
     buyer_price: u64,
@@ -306,9 +778,14 @@ pub fn calculate_fees(
     taker_fee_bp: u16,
     payer: &AccountInfo,
     seller: &AccountInfo,
+    fallback_fee_bp: (i16, u16),
+    collection_override: Option<&CollectionOverride>,
+    fee_cap: Option<FeeCap>,
 ) -> Result<FeeResults> {
+    let fallback_fee_bp =
+        collection_override.map_or(fallback_fee_bp, |o| (o.maker_fee_bp, o.taker_fee_bp));
     let (actual_maker_fee_bp, actual_taker_fee_bp) =
-        get_actual_maker_taker_fee_bp(notary, maker_fee_bp, taker_fee_bp);
+        get_actual_maker_taker_fee_bp(notary, maker_fee_bp, taker_fee_bp, fallback_fee_bp);
 
     assert_valid_fees_bp(actual_maker_fee_bp, actual_taker_fee_bp.try_into().unwrap())?;
 
@@ -322,6 +799,17 @@ pub fn calculate_fees(
         .ok_or(ErrorCode::NumericalOverflow)?
         .checked_div(10000)
         .ok_or(ErrorCode::NumericalOverflow)? as u64;
+    let (maker_fee, taker_fee) = match fee_cap {
+        Some(fee_cap) => apply_fee_cap(
+            &fee_cap,
+            buyer_price,
+            maker_fee,
+            actual_maker_fee_bp,
+            taker_fee,
+            actual_taker_fee_bp,
+        ),
+        None => (maker_fee, taker_fee),
+    };
     let seller_will_get_from_buyer = if payer.key.eq(seller.key) {
         (buyer_price as i64)
             .checked_add(maker_fee)
@@ -346,14 +834,93 @@ pub fn calculate_fees(
     })
 }
 
+// Fees for assets sold together, each asset is charged on its share of the price with its own
+// override, as if it was sold on its own.
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_item_fees(
+    notary: &AccountInfo,
+    items: &[(u64, Option<CollectionOverride>)],
+    maker_fee_bp: i16,
+    taker_fee_bp: u16,
+    payer: &AccountInfo,
+    seller: &AccountInfo,
+    fallback_fee_bp: (i16, u16),
+    fee_cap: Option<FeeCap>,
+) -> Result<FeeResults> {
+    let mut total = FeeResults {
+        maker_fee: 0,
+        taker_fee: 0,
+        seller_will_get_from_buyer: 0,
+        total_platform_fee: 0,
+    };
+    for (item_price, collection_override) in items {
+        let fees = calculate_fees(
+            notary,
+            *item_price,
+            maker_fee_bp,
+            taker_fee_bp,
+            payer,
+            seller,
+            fallback_fee_bp,
+            collection_override.as_ref(),
+            fee_cap,
+        )?;
+        total.maker_fee = total
+            .maker_fee
+            .checked_add(fees.maker_fee)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        total.taker_fee = total
+            .taker_fee
+            .checked_add(fees.taker_fee)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        total.seller_will_get_from_buyer = total
+            .seller_will_get_from_buyer
+            .checked_add(fees.seller_will_get_from_buyer)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        total.total_platform_fee = total
+            .total_platform_fee
+            .checked_add(fees.total_platform_fee)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+    }
+    Ok(total)
+}
+
+// The floor only applies to fees with a non-zero bp on a non-zero price, so fee free trades and
+// swaps without lamports stay free. A maker rebate never exceeds the taker fee, keeping the
+// platform fee non-negative like assert_valid_fees_bp does for the bps.
+fn apply_fee_cap(
+    fee_cap: &FeeCap,
+    buyer_price: u64,
+    maker_fee: i64,
+    maker_fee_bp: i16,
+    taker_fee: u64,
+    taker_fee_bp: u16,
+) -> (i64, u64) {
+    let taker_fee = if taker_fee_bp > 0 && buyer_price > 0 {
+        taker_fee.max(fee_cap.min_fee).min(fee_cap.max_fee)
+    } else {
+        taker_fee
+    };
+    let maker_fee = if maker_fee_bp > 0 {
+        (maker_fee as u64)
+            .max(fee_cap.min_fee)
+            .min(fee_cap.max_fee)
+            .min(buyer_price) as i64
+    } else {
+        maker_fee.max(-(taker_fee as i64))
+    };
+    (maker_fee, taker_fee)
+}
+
 pub fn get_actual_maker_taker_fee_bp(
     notary: &AccountInfo,
     maker_fee_bp: i16,
     taker_fee_bp: u16,
+    fallback_fee_bp: (i16, u16),
 ) -> (i16, u16) {
     match notary.is_signer {
         true => (maker_fee_bp, taker_fee_bp),
-        false => (DEFAULT_MAKER_FEE_BP, DEFAULT_TAKER_FEE_BP),
+        false => fallback_fee_bp,
     }
 }
 
@@ -374,3 +941,244 @@ pub fn assert_valid_fees_bp(maker_fee_bp: i16, taker_fee_bp: i16) -> Result<()>
 
     Ok(())
 }
+
+/// Checks that an Ed25519 program instruction earlier in the transaction verified that
+/// `message` was signed by `signer`. The signature itself is checked by the Ed25519 program,
+/// we only need to make sure the pubkey and message it checked are the ones we expect.
+pub fn verify_ed25519_ix(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+
+    for index in 0..current_index {
+        let ix = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+        if ed25519_ix_contains(&ix.data, signer, message) {
+            return Ok(());
+        }
+    }
+
+    msg!("No ed25519 signature found for signer: {}", signer);
+    Err(ErrorCode::InvalidSignature.into())
+}
+
+// Ed25519 instruction data layout:
+// num_signatures(u8), padding(u8), then per signature 7 little-endian u16 offsets:
+// signature_offset, signature_ix_index, pubkey_offset, pubkey_ix_index,
+// message_offset, message_size, message_ix_index.
+fn ed25519_ix_contains(data: &[u8], signer: &Pubkey, message: &[u8]) -> bool {
+    const OFFSETS_START: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+
+    let read_u16 = |offset: usize| -> Option<u16> {
+        data.get(offset..offset + 2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+    };
+    let num_signatures = match data.first() {
+        Some(num_signatures) => *num_signatures as usize,
+        None => return false,
+    };
+
+    (0..num_signatures).any(|i| {
+        let start = OFFSETS_START + i * OFFSETS_LEN;
+        let offsets: Option<Vec<u16>> = (0..7).map(|j| read_u16(start + j * 2)).collect();
+        let offsets = match offsets {
+            Some(offsets) => offsets,
+            None => return false,
+        };
+        // Signature, pubkey and message must all live in the Ed25519 instruction itself.
+        if offsets[1] != u16::MAX || offsets[3] != u16::MAX || offsets[6] != u16::MAX {
+            return false;
+        }
+        let pubkey_offset = offsets[2] as usize;
+        let message_offset = offsets[4] as usize;
+        let message_size = offsets[5] as usize;
+
+        data.get(pubkey_offset..pubkey_offset + 32) == Some(signer.as_ref())
+            && data.get(message_offset..message_offset + message_size) == Some(message)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak::hashv(&[&a, &b]).to_bytes()
+        } else {
+            keccak::hashv(&[&b, &a]).to_bytes()
+        }
+    }
+
+    #[test]
+    fn merkle_proof_verifies_every_leaf() {
+        let leaves: Vec<[u8; 32]> = (0u8..4).map(|i| keccak::hash(&[i]).to_bytes()).collect();
+        let left = hash_pair(leaves[0], leaves[1]);
+        let right = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(left, right);
+
+        assert!(verify_merkle_proof(&[leaves[1], right], root, leaves[0]));
+        assert!(verify_merkle_proof(&[leaves[0], right], root, leaves[1]));
+        assert!(verify_merkle_proof(&[leaves[3], left], root, leaves[2]));
+        assert!(verify_merkle_proof(&[leaves[2], left], root, leaves[3]));
+        // A single leaf tree is its own root.
+        assert!(verify_merkle_proof(&[], leaves[0], leaves[0]));
+    }
+
+    #[test]
+    fn merkle_proof_rejects_wrong_leaf_or_proof() {
+        let leaves: Vec<[u8; 32]> = (0u8..4).map(|i| keccak::hash(&[i]).to_bytes()).collect();
+        let left = hash_pair(leaves[0], leaves[1]);
+        let right = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(left, right);
+
+        assert!(!verify_merkle_proof(&[leaves[1], right], root, leaves[2]));
+        assert!(!verify_merkle_proof(&[leaves[1]], root, leaves[0]));
+        assert!(!verify_merkle_proof(&[leaves[1], left], root, leaves[0]));
+        assert!(!verify_merkle_proof(&[], root, leaves[0]));
+    }
+
+    fn fee_cap(min_fee: u64, max_fee: u64) -> FeeCap {
+        FeeCap {
+            payment_mint: Pubkey::default(),
+            min_fee,
+            max_fee,
+        }
+    }
+
+    #[test]
+    fn fee_cap_bounds_fees() {
+        let cap = fee_cap(100, 1000);
+        // Within bounds.
+        assert_eq!(apply_fee_cap(&cap, 10_000, 200, 200, 250, 250), (200, 250));
+        // Below the floor.
+        assert_eq!(apply_fee_cap(&cap, 1_000, 20, 200, 25, 250), (100, 100));
+        // Above the ceiling.
+        assert_eq!(
+            apply_fee_cap(&cap, 1_000_000, 20_000, 200, 25_000, 250),
+            (1000, 1000)
+        );
+    }
+
+    #[test]
+    fn fee_cap_keeps_free_trades_free() {
+        let cap = fee_cap(100, 1000);
+        assert_eq!(apply_fee_cap(&cap, 1_000, 0, 0, 0, 0), (0, 0));
+        assert_eq!(apply_fee_cap(&cap, 1_000, 0, 0, 25, 250), (0, 100));
+        assert_eq!(apply_fee_cap(&cap, 0, 0, 0, 0, 250), (0, 0));
+    }
+
+    #[test]
+    fn fee_cap_limits_maker_fee_to_price_and_rebate_to_taker_fee() {
+        let cap = fee_cap(100, 1000);
+        // The floor can't take more than the price from the seller.
+        assert_eq!(apply_fee_cap(&cap, 50, 1, 200, 1, 250), (50, 100));
+        // The rebate is bounded by the capped taker fee.
+        assert_eq!(
+            apply_fee_cap(&cap, 1_000_000, -5_000, -50, 25_000, 250),
+            (-1000, 1000)
+        );
+        assert_eq!(apply_fee_cap(&cap, 10_000, -50, -50, 250, 250), (-50, 250));
+    }
+
+    #[test]
+    fn linear_curve_moves_by_delta() {
+        assert_eq!(
+            curve_price_up(CurveType::Linear, 1_000, 100).unwrap(),
+            1_100
+        );
+        assert_eq!(
+            curve_price_down(CurveType::Linear, 1_000, 100).unwrap(),
+            900
+        );
+        assert_eq!(curve_price_down(CurveType::Linear, 100, 100).unwrap(), 0);
+        assert!(curve_price_up(CurveType::Linear, u64::MAX, 1).is_err());
+        assert!(curve_price_down(CurveType::Linear, 99, 100).is_err());
+    }
+
+    #[test]
+    fn exponential_curve_moves_by_delta_bp() {
+        assert_eq!(
+            curve_price_up(CurveType::Exponential, 1_000_000, 500).unwrap(),
+            1_050_000
+        );
+        assert_eq!(
+            curve_price_down(CurveType::Exponential, 1_050_000, 500).unwrap(),
+            1_000_000
+        );
+        assert_eq!(
+            curve_price_up(CurveType::Exponential, 1_000_000, 0).unwrap(),
+            1_000_000
+        );
+        assert!(curve_price_up(CurveType::Exponential, u64::MAX, 10_000).is_err());
+    }
+
+    // Builds Ed25519 instruction data with one signature, laid out like
+    // new_ed25519_instruction: offsets, pubkey, signature, message.
+    fn ed25519_ix_data(signer: &Pubkey, message: &[u8], ix_index: u16) -> Vec<u8> {
+        let pubkey_offset: u16 = 2 + 14;
+        let signature_offset = pubkey_offset + 32;
+        let message_offset = signature_offset + 64;
+        let offsets = [
+            signature_offset,
+            ix_index,
+            pubkey_offset,
+            ix_index,
+            message_offset,
+            message.len() as u16,
+            ix_index,
+        ];
+        let mut data = vec![1, 0];
+        for offset in offsets.iter() {
+            data.extend_from_slice(&offset.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn ed25519_ix_matches_signer_and_message() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_ix_data(&signer, b"order", u16::MAX);
+
+        assert!(ed25519_ix_contains(&data, &signer, b"order"));
+        assert!(!ed25519_ix_contains(&data, &Pubkey::new_unique(), b"order"));
+        assert!(!ed25519_ix_contains(&data, &signer, b"other"));
+        assert!(!ed25519_ix_contains(&data, &signer, b"orde"));
+    }
+
+    #[test]
+    fn ed25519_ix_rejects_data_from_other_instructions() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_ix_data(&signer, b"order", 0);
+
+        assert!(!ed25519_ix_contains(&data, &signer, b"order"));
+    }
+
+    #[test]
+    fn ed25519_ix_rejects_malformed_data() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_ix_data(&signer, b"order", u16::MAX);
+
+        assert!(!ed25519_ix_contains(&[], &signer, b"order"));
+        // Claims more signatures than it has offsets for, only the well formed one can match.
+        let mut extra_signatures = data.clone();
+        extra_signatures[0] = 2;
+        assert!(ed25519_ix_contains(&extra_signatures, &signer, b"order"));
+        assert!(!ed25519_ix_contains(&extra_signatures, &signer, b"other"));
+        // Offsets pointing past the end of the data.
+        assert!(!ed25519_ix_contains(&data[..40], &signer, b"order"));
+        assert!(!ed25519_ix_contains(
+            &data[..data.len() - 1],
+            &signer,
+            b"order"
+        ));
+    }
+}
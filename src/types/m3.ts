@@ -59,6 +59,11 @@ export type M3 = {
           "name": "sellerTradeState",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -142,6 +147,63 @@ export type M3 = {
           "name": "receiver",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payoutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeOverrideReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
         }
       ],
       "args": [
@@ -151,7 +213,24 @@ export type M3 = {
             "defined": "BuyNowArgs"
           }
         }
-      ]
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "closeFeeOverrideReceipt",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeOverrideReceipt",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
       "name": "cancelSell",
@@ -205,6 +284,21 @@ export type M3 = {
           "name": "sellerTradeState",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -215,362 +309,216 @@ export type M3 = {
           }
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "sellerTradeState",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "seller",
-            "type": "publicKey"
-          },
-          {
-            "name": "sellerReferral",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyerPrice",
-            "type": "u64"
-          },
-          {
-            "name": "assetId",
-            "type": "publicKey"
-          },
-          {
-            "name": "paymentMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "merkleTree",
-            "type": "publicKey"
-          },
-          {
-            "name": "index",
-            "type": "u32"
-          },
-          {
-            "name": "createdAt",
-            "type": "i64"
-          },
-          {
-            "name": "updatedAt",
-            "type": "i64"
+      "name": "buySignedOrder",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orderReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuySignedOrderArgs"
           }
-        ]
-      }
-    }
-  ],
-  "types": [
+        }
+      ]
+    },
     {
-      "name": "BuyNowArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "buyerPrice",
-            "type": "u64"
-          },
-          {
-            "name": "makerFeeBp",
-            "type": "i16"
-          },
-          {
-            "name": "takerFeeBp",
-            "type": "u16"
-          },
-          {
-            "name": "buyerCreatorRoyaltyBp",
-            "type": "u16"
-          },
-          {
-            "name": "creatorShares",
-            "type": {
-              "vec": "u16"
-            }
-          },
-          {
-            "name": "creatorVerified",
-            "type": {
-              "vec": "bool"
-            }
-          },
-          {
-            "name": "sellerFeeBasisPoints",
-            "type": "u16"
-          },
-          {
-            "name": "root",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "metadataHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "creatorHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "nonce",
-            "type": "u64"
-          },
-          {
-            "name": "index",
-            "type": "u32"
-          }
-        ]
-      }
-    },
-    {
-      "name": "CancelSellArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "root",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "dataHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "creatorHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "nonce",
-            "type": "u64"
-          },
-          {
-            "name": "index",
-            "type": "u32"
-          }
-        ]
-      }
-    },
-    {
-      "name": "SellArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "root",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "dataHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "creatorHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "nonce",
-            "type": "u64"
-          },
-          {
-            "name": "index",
-            "type": "u32"
-          },
-          {
-            "name": "buyerPrice",
-            "type": "u64"
-          },
-          {
-            "name": "paymentMint",
-            "type": "publicKey"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "IncorrectOwner",
-      "msg": "IncorrectOwner"
-    },
-    {
-      "code": 6001,
-      "name": "AssetIDMismatch",
-      "msg": "Asset ID does not match expected ID"
-    },
-    {
-      "code": 6002,
-      "name": "NoValidSignerPresent",
-      "msg": "No valid signer present"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidNotary",
-      "msg": "Invalid notary"
-    },
-    {
-      "code": 6004,
-      "name": "EmptyTradeState",
-      "msg": "Empty trade state"
-    },
-    {
-      "code": 6005,
-      "name": "InvalidPrice",
-      "msg": "Invalid price"
-    },
-    {
-      "code": 6006,
-      "name": "InvalidDiscriminator",
-      "msg": "Invalid discriminator"
-    },
-    {
-      "code": 6007,
-      "name": "InvalidPlatformFeeBp",
-      "msg": "Invalid platform fee bp"
-    },
-    {
-      "code": 6008,
-      "name": "NumericalOverflow",
-      "msg": "NumericalOverflow"
-    },
-    {
-      "code": 6009,
-      "name": "MismatchedCreatorDataLengths",
-      "msg": "MismatchedCreatorDataLengths"
-    },
-    {
-      "code": 6010,
-      "name": "CreatorShareTotalMustBe100",
-      "msg": "CreatorShareTotalMustBe100"
-    },
-    {
-      "code": 6011,
-      "name": "InvalidMakerTakerFee",
-      "msg": "InvalidMakerTakerFee"
-    },
-    {
-      "code": 6012,
-      "name": "InvalidCreators",
-      "msg": "InvalidCreators"
-    },
-    {
-      "code": 6013,
-      "name": "SellerMismatch",
-      "msg": "SellerMismatch"
-    }
-  ]
-};
-
-export const IDL: M3 = {
-  "version": "0.1.0",
-  "name": "m3",
-  "instructions": [
-    {
-      "name": "sell",
+      "name": "closeSignedOrderReceipt",
       "accounts": [
         {
-          "name": "wallet",
+          "name": "buyer",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "programAsSigner",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "treeAuthority",
+          "name": "orderReceipt",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "bumpNonce",
+      "accounts": [
         {
-          "name": "leafDelegate",
-          "isMut": false,
-          "isSigner": false
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "merkleTree",
+          "name": "userState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "logWrapper",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestCancelSell",
+      "accounts": [
         {
-          "name": "bubblegumProgram",
+          "name": "wallet",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "systemProgram",
+          "name": "merkleTree",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "compressionProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RequestCancelSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "sellerReferral",
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateSellerCancelCooldown",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "sellerTradeState",
+          "name": "config",
           "isMut": true,
           "isSigner": false
         }
@@ -579,39 +527,157 @@ export const IDL: M3 = {
         {
           "name": "args",
           "type": {
-            "defined": "SellArgs"
+            "defined": "UpdateSellerCancelCooldownArgs"
           }
         }
       ]
     },
     {
-      "name": "buyNow",
+      "name": "createPool",
       "accounts": [
         {
-          "name": "buyer",
+          "name": "owner",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "seller",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "notary",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreatePoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePool",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "platformTreasury",
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdatePoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "poolDepositSol",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PoolDepositSolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "poolWithdrawSol",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PoolWithdrawSolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closePool",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "poolDepositNft",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "treeAuthority",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "merkleTree",
           "isMut": true,
@@ -638,7 +704,30 @@ export const IDL: M3 = {
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
+          "name": "poolAsset",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PoolDepositNftArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "poolWithdrawNft",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
@@ -648,38 +737,103 @@ export const IDL: M3 = {
           "isSigner": false
         },
         {
-          "name": "sellerReferral",
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "poolAsset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "BuyNowArgs"
+            "defined": "PoolWithdrawNftArgs"
           }
         }
       ]
     },
     {
-      "name": "cancelSell",
+      "name": "buyFromPool",
       "accounts": [
         {
-          "name": "wallet",
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "programAsSigner",
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolAsset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "treeAuthority",
           "isMut": true,
@@ -711,12 +865,22 @@ export const IDL: M3 = {
           "isSigner": false
         },
         {
-          "name": "notary",
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolOwnerStats",
           "isMut": true,
           "isSigner": false
         }
@@ -725,42 +889,10070 @@ export const IDL: M3 = {
         {
           "name": "args",
           "type": {
-            "defined": "CancelSellArgs"
+            "defined": "BuyFromPoolArgs"
           }
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "sellerTradeState",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "seller",
-            "type": "publicKey"
-          },
-          {
-            "name": "sellerReferral",
-            "type": "publicKey"
-          },
-          {
+      "name": "sellIntoPool",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolAsset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolOwnerStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellIntoPoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellBundle",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bundleTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellBundleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyBundle",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bundleTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyBundleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelBundle",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bundleTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CancelBundleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createSwap",
+      "accounts": [
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "makerUserState",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateSwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "fillSwap",
+      "accounts": [
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "makerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "takerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "makerStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "FillSwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelSwap",
+      "accounts": [
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "makerUserState",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CancelSwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferListing",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newWallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "merkleTree",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "walletUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newWalletUserState",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferListingArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeeTiers",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateFeeTiersArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeeRecipients",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateFeeRecipientsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeeCaps",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateFeeCapsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyerEscrowDeposit",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyerEscrowDepositArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyerEscrowWithdraw",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyerEscrow",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyerEscrowWithdrawArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyNowWithIntent",
+      "accounts": [
+        {
+          "name": "relayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payoutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "intentReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeOverrideReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyNowWithIntentArgs"
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "closeBuyIntentReceipt",
+      "accounts": [
+        {
+          "name": "relayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "intentReceipt",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRoyaltyRedirect",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "royaltyRedirect",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRoyaltyRedirectArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "layawayDeposit",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "merkleTree",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "LayawayDepositArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "layawayDefault",
+      "accounts": [
+        {
+          "name": "merkleTree",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payoutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "layawayBuyer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "LayawayDefaultArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "listRental",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentalListing",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ListRentalArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "rentAsset",
+      "accounts": [
+        {
+          "name": "renter",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentalListing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RentAssetArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeRental",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentalListing",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CloseRentalArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createLoanOffer",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "loanOffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lenderUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateLoanOfferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelLoanOffer",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "loanOffer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "takeLoan",
+      "accounts": [
+        {
+          "name": "borrower",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "loanOffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lenderEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lenderUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TakeLoanArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "repayLoan",
+      "accounts": [
+        {
+          "name": "borrower",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RepayLoanArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "forecloseLoan",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "borrower",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ForecloseLoanArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createLaunchpad",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateLaunchpadArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mintBuy",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintCount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionAuthorityRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MintBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createTraitBid",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "traitBid",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateTraitBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelTraitBid",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "traitBid",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fillTraitBid",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "traitBid",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "FillTraitBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCollectionOverride",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetCollectionOverrideArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "sellerTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "sellerNonce",
+            "type": "u64"
+          },
+          {
+            "name": "cancelRequestedAt",
+            "type": "i64"
+          },
+          {
+            "name": "payoutAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "priceVersion",
+            "type": "u64"
+          },
+          {
+            "name": "pendingBuyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "pendingPriceEffectiveAt",
+            "type": "i64"
+          },
+          {
+            "name": "priceIncreaseDelaySeconds",
+            "type": "i64"
+          },
+          {
+            "name": "layawayEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "layawayPenaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "layawayBuyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "layawayDeposit",
+            "type": "u64"
+          },
+          {
+            "name": "layawayDeadline",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "signedOrderReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "filledAt",
+            "type": "i64"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeOverrideReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "buyIntentReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "filledAt",
+            "type": "i64"
+          },
+          {
+            "name": "relayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bundleTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bundleId",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerNonce",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "BundleItem"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "swapOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maker",
+            "type": "publicKey"
+          },
+          {
+            "name": "swapId",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "makerNonce",
+            "type": "u64"
+          },
+          {
+            "name": "lamports",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "offered",
+            "type": {
+              "vec": {
+                "defined": "BundleItem"
+              }
+            }
+          },
+          {
+            "name": "wanted",
+            "type": {
+              "vec": {
+                "defined": "SwapWant"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "curveType",
+            "type": {
+              "defined": "CurveType"
+            }
+          },
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "delta",
+            "type": "u64"
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "nftCount",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "poolAsset",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "feeTiers",
+            "type": {
+              "vec": {
+                "defined": "FeeTier"
+              }
+            }
+          },
+          {
+            "name": "feeRecipients",
+            "type": {
+              "vec": {
+                "defined": "FeeRecipient"
+              }
+            }
+          },
+          {
+            "name": "feeCaps",
+            "type": {
+              "vec": {
+                "defined": "FeeCap"
+              }
+            }
+          },
+          {
+            "name": "sellerCancelCooldownSeconds",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionOverride",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": "OverrideKind"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "buyerEscrow",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltyRedirect",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "destination",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rentalListing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pricePerPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "periodSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "maxPeriods",
+            "type": "u32"
+          },
+          {
+            "name": "renter",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentalEnd",
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "loanOffer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lender",
+            "type": "publicKey"
+          },
+          {
+            "name": "offerId",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lenderNonce",
+            "type": "u64"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "principal",
+            "type": "u64"
+          },
+          {
+            "name": "interestBp",
+            "type": "u16"
+          },
+          {
+            "name": "durationSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "loan",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lender",
+            "type": "publicKey"
+          },
+          {
+            "name": "borrower",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "principal",
+            "type": "u64"
+          },
+          {
+            "name": "repayAmount",
+            "type": "u64"
+          },
+          {
+            "name": "dueAt",
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "launchpad",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "launchpadId",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "supply",
+            "type": "u32"
+          },
+          {
+            "name": "minted",
+            "type": "u32"
+          },
+          {
+            "name": "startAt",
+            "type": "i64"
+          },
+          {
+            "name": "metadataTemplate",
+            "type": "bytes"
+          },
+          {
+            "name": "phases",
+            "type": {
+              "vec": {
+                "defined": "LaunchpadPhase"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "launchpadMintCount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "launchpad",
+            "type": "publicKey"
+          },
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "minted",
+            "type": {
+              "array": [
+                "u32",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "traitBid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidId",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "buyerNonce",
+            "type": "u64"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "traitRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u32"
+          },
+          {
+            "name": "filled",
+            "type": "u32"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "traderStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lastDay",
+            "type": "i64"
+          },
+          {
+            "name": "dailyVolume",
+            "type": {
+              "array": [
+                "u64",
+                30
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "BuyBundleItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyBundleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "BuyBundleItemArgs"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyFromPoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyIntent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "receiver",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyNowWithIntentArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "intent",
+            "type": {
+              "defined": "BuyIntent"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "feeOverride",
+            "type": {
+              "option": {
+                "defined": "FeeOverride"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeOverride",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyNowArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "priceVersion",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "feeOverride",
+            "type": {
+              "option": {
+                "defined": "FeeOverride"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SignedOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerNonce",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuySignedOrderArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "order",
+            "type": {
+              "defined": "SignedOrder"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyerEscrowDepositArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyerEscrowWithdrawArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelBundleItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelBundleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "CancelBundleItemArgs"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelSwapItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelSwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offered",
+            "type": {
+              "vec": {
+                "defined": "CancelSwapItemArgs"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CloseRentalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateLaunchpadArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "launchpadId",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "supply",
+            "type": "u32"
+          },
+          {
+            "name": "startAt",
+            "type": "i64"
+          },
+          {
+            "name": "metadataTemplate",
+            "type": "bytes"
+          },
+          {
+            "name": "phases",
+            "type": {
+              "vec": {
+                "defined": "LaunchpadPhase"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateLoanOfferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "principal",
+            "type": "u64"
+          },
+          {
+            "name": "interestBp",
+            "type": "u16"
+          },
+          {
+            "name": "durationSeconds",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "curveType",
+            "type": {
+              "defined": "CurveType"
+            }
+          },
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "delta",
+            "type": "u64"
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateSwapItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateSwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swapId",
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
+            "type": "u64"
+          },
+          {
+            "name": "offered",
+            "type": {
+              "vec": {
+                "defined": "CreateSwapItemArgs"
+              }
+            }
+          },
+          {
+            "name": "wanted",
+            "type": {
+              "vec": {
+                "defined": "SwapWant"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateTraitBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidId",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "traitRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u32"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FillSwapOfferedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FillSwapWantedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FillSwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "offered",
+            "type": {
+              "vec": {
+                "defined": "FillSwapOfferedArgs"
+              }
+            }
+          },
+          {
+            "name": "wanted",
+            "type": {
+              "vec": {
+                "defined": "FillSwapWantedArgs"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FillTraitBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "traitProof",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ForecloseLoanArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LayawayDefaultArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LayawayDepositArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListRentalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pricePerPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "periodSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "maxPeriods",
+            "type": "u32"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "phase",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "allowlistProof",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolDepositNftArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolDepositSolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolWithdrawNftArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolWithdrawSolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RentAssetArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "periods",
+            "type": "u32"
+          },
+          {
+            "name": "maxPricePerPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RepayLoanArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RequestCancelSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellBundleItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellBundleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundleId",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "SellBundleItemArgs"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellIntoPoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minPrice",
+            "type": "u64"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "payoutAddress",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "layawayPenaltyBp",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "priceIncreaseDelaySeconds",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetCollectionOverrideArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": "OverrideKind"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorRoyaltyBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRoyaltyRedirectArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "destination",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TakeLoanArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minPrincipal",
+            "type": "u64"
+          },
+          {
+            "name": "maxInterestBp",
+            "type": "u16"
+          },
+          {
+            "name": "minDurationSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferListingArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateFeeCapsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeCaps",
+            "type": {
+              "vec": {
+                "defined": "FeeCap"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateFeeRecipientsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeRecipients",
+            "type": {
+              "vec": {
+                "defined": "FeeRecipient"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateFeeTiersArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeTiers",
+            "type": {
+              "vec": {
+                "defined": "FeeTier"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "curveType",
+            "type": {
+              "defined": "CurveType"
+            }
+          },
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "delta",
+            "type": "u64"
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateSellerCancelCooldownArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sellerCancelCooldownSeconds",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapWant",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "kind",
+            "type": {
+              "defined": "SwapWantKind"
+            }
+          },
+          {
+            "name": "key",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minVolume",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeRecipient",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "shareBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeCap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "minFee",
+            "type": "u64"
+          },
+          {
+            "name": "maxFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LaunchpadPhase",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "walletCap",
+            "type": "u32"
+          },
+          {
+            "name": "startAt",
+            "type": "i64"
+          },
+          {
+            "name": "endAt",
+            "type": "i64"
+          },
+          {
+            "name": "allowlistRoot",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapWantKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "AssetId"
+          },
+          {
+            "name": "Collection"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear"
+          },
+          {
+            "name": "Exponential"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OverrideKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Collection"
+          },
+          {
+            "name": "CreatorHash"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "IncorrectOwner",
+      "msg": "IncorrectOwner"
+    },
+    {
+      "code": 6001,
+      "name": "AssetIDMismatch",
+      "msg": "Asset ID does not match expected ID"
+    },
+    {
+      "code": 6002,
+      "name": "NoValidSignerPresent",
+      "msg": "No valid signer present"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidNotary",
+      "msg": "Invalid notary"
+    },
+    {
+      "code": 6004,
+      "name": "EmptyTradeState",
+      "msg": "Empty trade state"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidPrice",
+      "msg": "Invalid price"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidDiscriminator",
+      "msg": "Invalid discriminator"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidPlatformFeeBp",
+      "msg": "Invalid platform fee bp"
+    },
+    {
+      "code": 6008,
+      "name": "NumericalOverflow",
+      "msg": "NumericalOverflow"
+    },
+    {
+      "code": 6009,
+      "name": "MismatchedCreatorDataLengths",
+      "msg": "MismatchedCreatorDataLengths"
+    },
+    {
+      "code": 6010,
+      "name": "CreatorShareTotalMustBe100",
+      "msg": "CreatorShareTotalMustBe100"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidMakerTakerFee",
+      "msg": "InvalidMakerTakerFee"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidCreators",
+      "msg": "InvalidCreators"
+    },
+    {
+      "code": 6013,
+      "name": "SellerMismatch",
+      "msg": "SellerMismatch"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidSignature",
+      "msg": "Missing or invalid ed25519 signature instruction"
+    },
+    {
+      "code": 6015,
+      "name": "OrderExpired",
+      "msg": "Order expired"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidPaymentMint",
+      "msg": "Invalid payment mint"
+    },
+    {
+      "code": 6017,
+      "name": "OrderNotExpired",
+      "msg": "Order not expired"
+    },
+    {
+      "code": 6018,
+      "name": "StaleNonce",
+      "msg": "Order nonce is stale"
+    },
+    {
+      "code": 6019,
+      "name": "CancelPending",
+      "msg": "Listing has a pending cancel request"
+    },
+    {
+      "code": 6020,
+      "name": "CancelCooldownNotElapsed",
+      "msg": "Cancel cooldown has not elapsed"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidCancelCooldown",
+      "msg": "Invalid seller cancel cooldown"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidPoolParams",
+      "msg": "Invalid pool parameters"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidCollection",
+      "msg": "Asset is not part of the collection"
+    },
+    {
+      "code": 6024,
+      "name": "InsufficientPoolBalance",
+      "msg": "Insufficient pool balance"
+    },
+    {
+      "code": 6025,
+      "name": "PoolNotEmpty",
+      "msg": "Pool is not empty"
+    },
+    {
+      "code": 6026,
+      "name": "SlippageExceeded",
+      "msg": "Price exceeds slippage limit"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidMetadataArgs",
+      "msg": "Invalid metadata args"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidBundleSize",
+      "msg": "Invalid bundle size"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidRemainingAccounts",
+      "msg": "Invalid remaining accounts"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidSwapItems",
+      "msg": "Invalid swap items"
+    },
+    {
+      "code": 6031,
+      "name": "PriceVersionMismatch",
+      "msg": "Listing price version does not match"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidPriceIncreaseDelay",
+      "msg": "Invalid price increase delay"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidFeeTiers",
+      "msg": "Invalid fee tiers"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidFeeRecipients",
+      "msg": "Invalid fee recipients"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidCollectionOverride",
+      "msg": "Invalid collection override"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidFeeCaps",
+      "msg": "Invalid fee caps"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidFeeOverride",
+      "msg": "Invalid fee override"
+    },
+    {
+      "code": 6038,
+      "name": "InsufficientEscrowBalance",
+      "msg": "Insufficient buyer escrow balance"
+    },
+    {
+      "code": 6039,
+      "name": "ListingLocked",
+      "msg": "Listing is locked by a layaway"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidLayaway",
+      "msg": "Invalid layaway"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidRental",
+      "msg": "Invalid rental"
+    },
+    {
+      "code": 6042,
+      "name": "AssetRented",
+      "msg": "Asset is currently rented"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidLoanOffer",
+      "msg": "Invalid loan offer"
+    },
+    {
+      "code": 6044,
+      "name": "LoanNotDue",
+      "msg": "Loan is not due yet"
+    },
+    {
+      "code": 6045,
+      "name": "LoanOverdue",
+      "msg": "Loan is overdue"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidLaunchpad",
+      "msg": "Invalid launchpad"
+    },
+    {
+      "code": 6047,
+      "name": "SaleNotStarted",
+      "msg": "Launchpad sale has not started"
+    },
+    {
+      "code": 6048,
+      "name": "SoldOut",
+      "msg": "Launchpad is sold out"
+    },
+    {
+      "code": 6049,
+      "name": "PhaseNotActive",
+      "msg": "Launchpad phase is not active"
+    },
+    {
+      "code": 6050,
+      "name": "NotAllowlisted",
+      "msg": "Wallet is not on the allowlist"
+    },
+    {
+      "code": 6051,
+      "name": "WalletCapReached",
+      "msg": "Wallet mint limit reached"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidTraitBid",
+      "msg": "Invalid trait bid"
+    },
+    {
+      "code": 6053,
+      "name": "AssetNotEligible",
+      "msg": "Asset is not eligible for the trait bid"
+    }
+  ]
+};
+
+export const IDL: M3 = {
+  "version": "0.1.0",
+  "name": "m3",
+  "instructions": [
+    {
+      "name": "sell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyNow",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payoutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeOverrideReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyNowArgs"
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "closeFeeOverrideReceipt",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeOverrideReceipt",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CancelSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buySignedOrder",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orderReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuySignedOrderArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeSignedOrderReceipt",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "orderReceipt",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "bumpNonce",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestCancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "merkleTree",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RequestCancelSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateSellerCancelCooldown",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateSellerCancelCooldownArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createPool",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreatePoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePool",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdatePoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "poolDepositSol",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PoolDepositSolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "poolWithdrawSol",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PoolWithdrawSolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closePool",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "poolDepositNft",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolAsset",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PoolDepositNftArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "poolWithdrawNft",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolAsset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PoolWithdrawNftArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyFromPool",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolAsset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolOwnerStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyFromPoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellIntoPool",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolAsset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolOwnerStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellIntoPoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellBundle",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bundleTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellBundleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyBundle",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bundleTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyBundleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelBundle",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bundleTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CancelBundleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createSwap",
+      "accounts": [
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "makerUserState",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateSwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "fillSwap",
+      "accounts": [
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "makerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "takerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "makerStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "FillSwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelSwap",
+      "accounts": [
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "makerUserState",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CancelSwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferListing",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newWallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "merkleTree",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "walletUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newWalletUserState",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferListingArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeeTiers",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateFeeTiersArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeeRecipients",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateFeeRecipientsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeeCaps",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateFeeCapsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyerEscrowDeposit",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyerEscrowDepositArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyerEscrowWithdraw",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyerEscrow",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyerEscrowWithdrawArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyNowWithIntent",
+      "accounts": [
+        {
+          "name": "relayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payoutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "intentReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeOverrideReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyNowWithIntentArgs"
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "closeBuyIntentReceipt",
+      "accounts": [
+        {
+          "name": "relayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "intentReceipt",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRoyaltyRedirect",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "royaltyRedirect",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRoyaltyRedirectArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "layawayDeposit",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "merkleTree",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "LayawayDepositArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "layawayDefault",
+      "accounts": [
+        {
+          "name": "merkleTree",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payoutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "layawayBuyer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "LayawayDefaultArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "listRental",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentalListing",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ListRentalArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "rentAsset",
+      "accounts": [
+        {
+          "name": "renter",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentalListing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RentAssetArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeRental",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentalListing",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CloseRentalArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createLoanOffer",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "loanOffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lenderUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateLoanOfferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelLoanOffer",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "loanOffer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "takeLoan",
+      "accounts": [
+        {
+          "name": "borrower",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "loanOffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lenderEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lenderUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TakeLoanArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "repayLoan",
+      "accounts": [
+        {
+          "name": "borrower",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RepayLoanArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "forecloseLoan",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "borrower",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ForecloseLoanArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createLaunchpad",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateLaunchpadArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mintBuy",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintCount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionAuthorityRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MintBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createTraitBid",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "traitBid",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateTraitBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelTraitBid",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "traitBid",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fillTraitBid",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorHashOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "traitBid",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "FillTraitBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCollectionOverride",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionOverride",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetCollectionOverrideArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "sellerTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "sellerNonce",
+            "type": "u64"
+          },
+          {
+            "name": "cancelRequestedAt",
+            "type": "i64"
+          },
+          {
+            "name": "payoutAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "priceVersion",
+            "type": "u64"
+          },
+          {
+            "name": "pendingBuyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "pendingPriceEffectiveAt",
+            "type": "i64"
+          },
+          {
+            "name": "priceIncreaseDelaySeconds",
+            "type": "i64"
+          },
+          {
+            "name": "layawayEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "layawayPenaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "layawayBuyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "layawayDeposit",
+            "type": "u64"
+          },
+          {
+            "name": "layawayDeadline",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "signedOrderReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "filledAt",
+            "type": "i64"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeOverrideReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "buyIntentReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "filledAt",
+            "type": "i64"
+          },
+          {
+            "name": "relayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bundleTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bundleId",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerNonce",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "BundleItem"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "swapOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maker",
+            "type": "publicKey"
+          },
+          {
+            "name": "swapId",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "makerNonce",
+            "type": "u64"
+          },
+          {
+            "name": "lamports",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "offered",
+            "type": {
+              "vec": {
+                "defined": "BundleItem"
+              }
+            }
+          },
+          {
+            "name": "wanted",
+            "type": {
+              "vec": {
+                "defined": "SwapWant"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "curveType",
+            "type": {
+              "defined": "CurveType"
+            }
+          },
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "delta",
+            "type": "u64"
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "nftCount",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "poolAsset",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "feeTiers",
+            "type": {
+              "vec": {
+                "defined": "FeeTier"
+              }
+            }
+          },
+          {
+            "name": "feeRecipients",
+            "type": {
+              "vec": {
+                "defined": "FeeRecipient"
+              }
+            }
+          },
+          {
+            "name": "feeCaps",
+            "type": {
+              "vec": {
+                "defined": "FeeCap"
+              }
+            }
+          },
+          {
+            "name": "sellerCancelCooldownSeconds",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionOverride",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": "OverrideKind"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "buyerEscrow",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltyRedirect",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "destination",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rentalListing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pricePerPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "periodSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "maxPeriods",
+            "type": "u32"
+          },
+          {
+            "name": "renter",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentalEnd",
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "loanOffer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lender",
+            "type": "publicKey"
+          },
+          {
+            "name": "offerId",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lenderNonce",
+            "type": "u64"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "principal",
+            "type": "u64"
+          },
+          {
+            "name": "interestBp",
+            "type": "u16"
+          },
+          {
+            "name": "durationSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "loan",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lender",
+            "type": "publicKey"
+          },
+          {
+            "name": "borrower",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "principal",
+            "type": "u64"
+          },
+          {
+            "name": "repayAmount",
+            "type": "u64"
+          },
+          {
+            "name": "dueAt",
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "launchpad",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "launchpadId",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "supply",
+            "type": "u32"
+          },
+          {
+            "name": "minted",
+            "type": "u32"
+          },
+          {
+            "name": "startAt",
+            "type": "i64"
+          },
+          {
+            "name": "metadataTemplate",
+            "type": "bytes"
+          },
+          {
+            "name": "phases",
+            "type": {
+              "vec": {
+                "defined": "LaunchpadPhase"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "launchpadMintCount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "launchpad",
+            "type": "publicKey"
+          },
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "minted",
+            "type": {
+              "array": [
+                "u32",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "traitBid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidId",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "buyerNonce",
+            "type": "u64"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "traitRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u32"
+          },
+          {
+            "name": "filled",
+            "type": "u32"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "traderStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lastDay",
+            "type": "i64"
+          },
+          {
+            "name": "dailyVolume",
+            "type": {
+              "array": [
+                "u64",
+                30
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "BuyBundleItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyBundleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "BuyBundleItemArgs"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyFromPoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyIntent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "receiver",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyNowWithIntentArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "intent",
+            "type": {
+              "defined": "BuyIntent"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "feeOverride",
+            "type": {
+              "option": {
+                "defined": "FeeOverride"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeOverride",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyNowArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "priceVersion",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "feeOverride",
+            "type": {
+              "option": {
+                "defined": "FeeOverride"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SignedOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerNonce",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuySignedOrderArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "order",
+            "type": {
+              "defined": "SignedOrder"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyerEscrowDepositArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyerEscrowWithdrawArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelBundleItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelBundleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "CancelBundleItemArgs"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelSwapItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelSwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offered",
+            "type": {
+              "vec": {
+                "defined": "CancelSwapItemArgs"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CloseRentalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateLaunchpadArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "launchpadId",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "supply",
+            "type": "u32"
+          },
+          {
+            "name": "startAt",
+            "type": "i64"
+          },
+          {
+            "name": "metadataTemplate",
+            "type": "bytes"
+          },
+          {
+            "name": "phases",
+            "type": {
+              "vec": {
+                "defined": "LaunchpadPhase"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateLoanOfferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "principal",
+            "type": "u64"
+          },
+          {
+            "name": "interestBp",
+            "type": "u16"
+          },
+          {
+            "name": "durationSeconds",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "curveType",
+            "type": {
+              "defined": "CurveType"
+            }
+          },
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "delta",
+            "type": "u64"
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateSwapItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateSwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swapId",
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
+            "type": "u64"
+          },
+          {
+            "name": "offered",
+            "type": {
+              "vec": {
+                "defined": "CreateSwapItemArgs"
+              }
+            }
+          },
+          {
+            "name": "wanted",
+            "type": {
+              "vec": {
+                "defined": "SwapWant"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateTraitBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidId",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "traitRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u32"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FillSwapOfferedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FillSwapWantedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FillSwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "offered",
+            "type": {
+              "vec": {
+                "defined": "FillSwapOfferedArgs"
+              }
+            }
+          },
+          {
+            "name": "wanted",
+            "type": {
+              "vec": {
+                "defined": "FillSwapWantedArgs"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FillTraitBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "traitProof",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ForecloseLoanArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LayawayDefaultArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LayawayDepositArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListRentalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pricePerPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "periodSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "maxPeriods",
+            "type": "u32"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "phase",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "allowlistProof",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolDepositNftArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolDepositSolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolWithdrawNftArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolWithdrawSolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RentAssetArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "periods",
+            "type": "u32"
+          },
+          {
+            "name": "maxPricePerPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorShares",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "creatorVerified",
+            "type": {
+              "vec": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RepayLoanArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RequestCancelSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellBundleItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellBundleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundleId",
+            "type": "publicKey"
+          },
+          {
             "name": "buyerPrice",
             "type": "u64"
           },
           {
-            "name": "assetId",
+            "name": "paymentMint",
             "type": "publicKey"
           },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "SellBundleItemArgs"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellIntoPoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minPrice",
+            "type": "u64"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
           {
             "name": "paymentMint",
             "type": "publicKey"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "payoutAddress",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "layawayPenaltyBp",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "priceIncreaseDelaySeconds",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetCollectionOverrideArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": "OverrideKind"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "creatorRoyaltyBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRoyaltyRedirectArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "destination",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TakeLoanArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minPrincipal",
+            "type": "u64"
+          },
+          {
+            "name": "maxInterestBp",
+            "type": "u16"
+          },
+          {
+            "name": "minDurationSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "metadataArgs",
+            "type": "bytes"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferListingArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateFeeCapsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeCaps",
+            "type": {
+              "vec": {
+                "defined": "FeeCap"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateFeeRecipientsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeRecipients",
+            "type": {
+              "vec": {
+                "defined": "FeeRecipient"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateFeeTiersArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeTiers",
+            "type": {
+              "vec": {
+                "defined": "FeeTier"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "curveType",
+            "type": {
+              "defined": "CurveType"
+            }
+          },
+          {
+            "name": "spotPrice",
+            "type": "u64"
           },
+          {
+            "name": "delta",
+            "type": "u64"
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateSellerCancelCooldownArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sellerCancelCooldownSeconds",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "merkleTree",
             "type": "publicKey"
@@ -770,25 +10962,37 @@ export const IDL: M3 = {
             "type": "u32"
           },
           {
-            "name": "createdAt",
-            "type": "i64"
+            "name": "assetId",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapWant",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "kind",
+            "type": {
+              "defined": "SwapWantKind"
+            }
           },
           {
-            "name": "updatedAt",
-            "type": "i64"
+            "name": "key",
+            "type": "publicKey"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "BuyNowArgs",
+      "name": "FeeTier",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "buyerPrice",
+            "name": "minVolume",
             "type": "u64"
           },
           {
@@ -798,155 +11002,119 @@ export const IDL: M3 = {
           {
             "name": "takerFeeBp",
             "type": "u16"
-          },
-          {
-            "name": "buyerCreatorRoyaltyBp",
-            "type": "u16"
-          },
-          {
-            "name": "creatorShares",
-            "type": {
-              "vec": "u16"
-            }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeRecipient",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "creatorVerified",
-            "type": {
-              "vec": "bool"
-            }
+            "name": "address",
+            "type": "publicKey"
           },
           {
-            "name": "sellerFeeBasisPoints",
+            "name": "shareBp",
             "type": "u16"
-          },
-          {
-            "name": "root",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "metadataHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeCap",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "creatorHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "paymentMint",
+            "type": "publicKey"
           },
           {
-            "name": "nonce",
+            "name": "minFee",
             "type": "u64"
           },
           {
-            "name": "index",
-            "type": "u32"
+            "name": "maxFee",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "CancelSellArgs",
+      "name": "LaunchpadPhase",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "root",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "price",
+            "type": "u64"
           },
           {
-            "name": "dataHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "walletCap",
+            "type": "u32"
           },
           {
-            "name": "creatorHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "startAt",
+            "type": "i64"
           },
           {
-            "name": "nonce",
-            "type": "u64"
+            "name": "endAt",
+            "type": "i64"
           },
           {
-            "name": "index",
-            "type": "u32"
+            "name": "allowlistRoot",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "SellArgs",
+      "name": "SwapWantKind",
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "root",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "dataHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "AssetId"
           },
           {
-            "name": "creatorHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
+            "name": "Collection"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveType",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "nonce",
-            "type": "u64"
+            "name": "Linear"
           },
           {
-            "name": "index",
-            "type": "u32"
-          },
+            "name": "Exponential"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OverrideKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "buyerPrice",
-            "type": "u64"
+            "name": "Collection"
           },
           {
-            "name": "paymentMint",
-            "type": "publicKey"
+            "name": "CreatorHash"
           }
         ]
       }
@@ -1022,6 +11190,206 @@ export const IDL: M3 = {
       "code": 6013,
       "name": "SellerMismatch",
       "msg": "SellerMismatch"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidSignature",
+      "msg": "Missing or invalid ed25519 signature instruction"
+    },
+    {
+      "code": 6015,
+      "name": "OrderExpired",
+      "msg": "Order expired"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidPaymentMint",
+      "msg": "Invalid payment mint"
+    },
+    {
+      "code": 6017,
+      "name": "OrderNotExpired",
+      "msg": "Order not expired"
+    },
+    {
+      "code": 6018,
+      "name": "StaleNonce",
+      "msg": "Order nonce is stale"
+    },
+    {
+      "code": 6019,
+      "name": "CancelPending",
+      "msg": "Listing has a pending cancel request"
+    },
+    {
+      "code": 6020,
+      "name": "CancelCooldownNotElapsed",
+      "msg": "Cancel cooldown has not elapsed"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidCancelCooldown",
+      "msg": "Invalid seller cancel cooldown"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidPoolParams",
+      "msg": "Invalid pool parameters"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidCollection",
+      "msg": "Asset is not part of the collection"
+    },
+    {
+      "code": 6024,
+      "name": "InsufficientPoolBalance",
+      "msg": "Insufficient pool balance"
+    },
+    {
+      "code": 6025,
+      "name": "PoolNotEmpty",
+      "msg": "Pool is not empty"
+    },
+    {
+      "code": 6026,
+      "name": "SlippageExceeded",
+      "msg": "Price exceeds slippage limit"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidMetadataArgs",
+      "msg": "Invalid metadata args"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidBundleSize",
+      "msg": "Invalid bundle size"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidRemainingAccounts",
+      "msg": "Invalid remaining accounts"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidSwapItems",
+      "msg": "Invalid swap items"
+    },
+    {
+      "code": 6031,
+      "name": "PriceVersionMismatch",
+      "msg": "Listing price version does not match"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidPriceIncreaseDelay",
+      "msg": "Invalid price increase delay"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidFeeTiers",
+      "msg": "Invalid fee tiers"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidFeeRecipients",
+      "msg": "Invalid fee recipients"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidCollectionOverride",
+      "msg": "Invalid collection override"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidFeeCaps",
+      "msg": "Invalid fee caps"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidFeeOverride",
+      "msg": "Invalid fee override"
+    },
+    {
+      "code": 6038,
+      "name": "InsufficientEscrowBalance",
+      "msg": "Insufficient buyer escrow balance"
+    },
+    {
+      "code": 6039,
+      "name": "ListingLocked",
+      "msg": "Listing is locked by a layaway"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidLayaway",
+      "msg": "Invalid layaway"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidRental",
+      "msg": "Invalid rental"
+    },
+    {
+      "code": 6042,
+      "name": "AssetRented",
+      "msg": "Asset is currently rented"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidLoanOffer",
+      "msg": "Invalid loan offer"
+    },
+    {
+      "code": 6044,
+      "name": "LoanNotDue",
+      "msg": "Loan is not due yet"
+    },
+    {
+      "code": 6045,
+      "name": "LoanOverdue",
+      "msg": "Loan is overdue"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidLaunchpad",
+      "msg": "Invalid launchpad"
+    },
+    {
+      "code": 6047,
+      "name": "SaleNotStarted",
+      "msg": "Launchpad sale has not started"
+    },
+    {
+      "code": 6048,
+      "name": "SoldOut",
+      "msg": "Launchpad is sold out"
+    },
+    {
+      "code": 6049,
+      "name": "PhaseNotActive",
+      "msg": "Launchpad phase is not active"
+    },
+    {
+      "code": 6050,
+      "name": "NotAllowlisted",
+      "msg": "Wallet is not on the allowlist"
+    },
+    {
+      "code": 6051,
+      "name": "WalletCapReached",
+      "msg": "Wallet mint limit reached"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidTraitBid",
+      "msg": "Invalid trait bid"
+    },
+    {
+      "code": 6053,
+      "name": "AssetNotEligible",
+      "msg": "Asset is not eligible for the trait bid"
     }
   ]
 };